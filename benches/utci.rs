@@ -0,0 +1,77 @@
+//! Throughput of UTCI over a large grid of inputs.
+//!
+//! Run with `cargo bench --bench utci`. Each entry in `IMPLEMENTATIONS` is benchmarked over the same
+//! inputs, so an alternative form of the polynomial can be compared against the current one by
+//! adding it to the list.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use thermofeel_rs::*;
+
+const GRID_SIZE: usize = 100_000;
+
+type Utci = fn(f64, f64, f64, f64) -> f64;
+
+const IMPLEMENTATIONS: &[(&str, Utci)] = &[("calculate_utci", |t2_k, va, mrt, td_k| {
+    calculate_utci(t2_k, va, mrt, Some(td_k), None)
+})];
+
+struct Inputs {
+    t2_k: Vec<f64>,
+    va: Vec<f64>,
+    mrt: Vec<f64>,
+    td_k: Vec<f64>,
+}
+
+/// Builds a deterministic spread of inputs covering the valid range of the polynomial.
+fn inputs(n: usize) -> Inputs {
+    let fraction = |i: usize, period: usize| (i % period) as f64 / period as f64;
+
+    Inputs {
+        t2_k: (0..n).map(|i| 233.15 + 80.0 * fraction(i, 97)).collect(),
+        va: (0..n).map(|i| 0.5 + 16.5 * fraction(i, 89)).collect(),
+        mrt: (0..n).map(|i| 233.15 + 110.0 * fraction(i, 83)).collect(),
+        td_k: (0..n).map(|i| 223.15 + 70.0 * fraction(i, 79)).collect(),
+    }
+}
+
+fn bench_utci(c: &mut Criterion) {
+    let inputs = inputs(GRID_SIZE);
+
+    let mut group = c.benchmark_group("utci");
+    group.throughput(Throughput::Elements(GRID_SIZE as u64));
+
+    for (name, utci) in IMPLEMENTATIONS {
+        group.bench_function(BenchmarkId::new("scalar", name), |b| {
+            b.iter(|| {
+                let mut sum = 0.0;
+                for i in 0..GRID_SIZE {
+                    sum += utci(
+                        black_box(inputs.t2_k[i]),
+                        black_box(inputs.va[i]),
+                        black_box(inputs.mrt[i]),
+                        black_box(inputs.td_k[i]),
+                    );
+                }
+                sum
+            })
+        });
+    }
+
+    group.bench_function("calculate_utci_slice", |b| {
+        b.iter(|| {
+            calculate_utci_slice(
+                black_box(&inputs.t2_k),
+                black_box(&inputs.va),
+                black_box(&inputs.mrt),
+                black_box(&inputs.td_k),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_utci);
+criterion_main!(benches);
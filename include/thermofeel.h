@@ -0,0 +1,94 @@
+/* Generated with cbindgen from the `ffi` feature of thermofeel-rs. Do not edit by hand. */
+
+#ifndef THERMOFEEL_H
+#define THERMOFEEL_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The result was written to `out`.
+#define TF_OK 0
+
+// The `out` pointer was null; nothing was written.
+#define TF_NULL_POINTER 1
+
+// A required input was not provided; NaN was written to `out`.
+#define TF_MISSING_INPUT 2
+
+// The index does not apply to the inputs; NaN was written to `out`.
+#define TF_NOT_APPLICABLE 3
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Calculates the Universal Thermal Climate Index (UTCI) in Kelvin, see [`calculate_utci`].
+//
+// `td_k` and `eh_pa` are optional and may be NaN, but at least one must be provided,
+// otherwise [`TF_MISSING_INPUT`] is returned.
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_utci(double t2_k, double va, double mrt, double td_k, double eh_pa, double *out);
+
+// Calculates Wet Bulb Globe Temperature (WBGT) in Kelvin, see [`calculate_wbgt`].
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_wbgt(double t2_k, double mrt, double va, double td_k, double *out);
+
+// Calculates the simplified Wet Bulb Globe Temperature in Kelvin, see [`calculate_wbgt_simple`].
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_wbgt_simple(double t2_k, double rh, double *out);
+
+// Calculates Humidex in Kelvin, see [`calculate_humidex`].
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_humidex(double t2_k, double td_k, double *out);
+
+// Calculates the apparent temperature in Kelvin, see [`calculate_apparent_temperature`].
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_apparent_temperature(double t2_k, double va, double rh, double *out);
+
+// Calculates the wind chill in Kelvin, see [`calculate_wind_chill`].
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_wind_chill(double t2_k, double va, double *out);
+
+// Calculates the simplified heat index in Kelvin, see [`calculate_heat_index_simplified`].
+//
+// Returns [`TF_NOT_APPLICABLE`] when the temperature is too low.
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_heat_index_simplified(double t2_k, double rh, double *out);
+
+// Calculates the adjusted heat index in Kelvin, see [`calculate_heat_index_adjusted`].
+//
+// Returns [`TF_NOT_APPLICABLE`] when the conditions are not met for calculation.
+//
+// # Safety
+//
+// `out` must be null or valid for writing an `f64`.
+int tf_calculate_heat_index_adjusted(double t2_k, double td_k, double *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* THERMOFEEL_H */
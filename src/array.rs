@@ -0,0 +1,61 @@
+use ndarray::{Array, ArrayView, Dimension, Zip};
+
+use crate::batch::calculate_utci_or_nan;
+
+fn broadcast_to<'a, D: Dimension, E: Dimension>(
+    input: &'a ArrayView<f64, E>,
+    dim: &D,
+    name: &str,
+) -> ArrayView<'a, f64, D> {
+    input.broadcast(dim.clone()).unwrap_or_else(|| {
+        panic!(
+            "`{name}` with shape {:?} cannot be broadcast to shape {:?}",
+            input.shape(),
+            dim.slice()
+        )
+    })
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) elementwise over an array.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is UTCI in Kelvin, with the same shape as `t2_k`. Elements where any input is
+/// NaN are NaN.
+///
+/// `va`, `mrt` and `td_k` are broadcast to the shape of `t2_k` using ndarray's broadcasting rules:
+/// axes are matched from the last one, and an axis of length 1 or a missing leading axis is repeated.
+/// A single value can be passed as a zero-dimensional array created with [`ndarray::arr0`].
+///
+/// # Panics
+///
+/// Panics if `va`, `mrt` or `td_k` cannot be broadcast to the shape of `t2_k`.
+pub fn calculate_utci_array<D, E1, E2, E3>(
+    t2_k: &ArrayView<f64, D>,
+    va: &ArrayView<f64, E1>,
+    mrt: &ArrayView<f64, E2>,
+    td_k: &ArrayView<f64, E3>,
+) -> Array<f64, D>
+where
+    D: Dimension,
+    E1: Dimension,
+    E2: Dimension,
+    E3: Dimension,
+{
+    let dim = t2_k.raw_dim();
+    let va = broadcast_to(va, &dim, "va");
+    let mrt = broadcast_to(mrt, &dim, "mrt");
+    let td_k = broadcast_to(td_k, &dim, "td_k");
+
+    Zip::from(t2_k)
+        .and(&va)
+        .and(&mrt)
+        .and(&td_k)
+        .map_collect(|&t2_k, &va, &mrt, &td_k| calculate_utci_or_nan(t2_k, va, mrt, td_k))
+}
@@ -0,0 +1,356 @@
+use crate::{
+    ThermofeelError, calculate_dew_point_from_relative_humidity, calculate_humidex,
+    calculate_mean_radiant_temperature, calculate_relative_humidity_percent, calculate_utci,
+    calculate_wbgt, calculate_wbt,
+};
+
+/// Calculates UTCI for one cell, returning NaN if any input is NaN.
+pub(crate) fn calculate_utci_or_nan(t2_k: f64, va: f64, mrt: f64, td_k: f64) -> f64 {
+    if t2_k.is_nan() || va.is_nan() || mrt.is_nan() || td_k.is_nan() {
+        f64::NAN
+    } else {
+        calculate_utci(t2_k, va, mrt, Some(td_k), None)
+    }
+}
+
+pub(crate) fn assert_same_len(expected: usize, slices: &[(&str, usize)]) {
+    for (name, len) in slices {
+        assert_eq!(
+            *len, expected,
+            "`{name}` has length {len} but `t2_k` has length {expected}"
+        );
+    }
+}
+
+fn check_same_len(expected: usize, lens: &[usize]) -> Result<(), ThermofeelError> {
+    match lens.iter().find(|&&got| got != expected) {
+        Some(&got) => Err(ThermofeelError::MismatchedSliceLengths { expected, got }),
+        None => Ok(()),
+    }
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) for each element of a set of slices.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is UTCI in Kelvin for each element. Elements where any input is NaN, such as
+/// fill values over oceans, are NaN.
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+pub fn calculate_utci_slice(t2_k: &[f64], va: &[f64], mrt: &[f64], td_k: &[f64]) -> Vec<f64> {
+    assert_same_len(
+        t2_k.len(),
+        &[("va", va.len()), ("mrt", mrt.len()), ("td_k", td_k.len())],
+    );
+
+    (0..t2_k.len())
+        .map(|i| calculate_utci_or_nan(t2_k[i], va[i], mrt[i], td_k[i]))
+        .collect()
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) for each element of a set of slices,
+/// without panicking on bad lengths.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is UTCI in Kelvin for each element, as [`calculate_utci_slice`]. Every slice
+/// must have the same length as `t2_k`; otherwise the return value is a
+/// [`ThermofeelError::MismatchedSliceLengths`] naming the length of `t2_k` and that of the first
+/// slice, in argument order, that differs from it. Nothing is computed in that case.
+pub fn calculate_utci_slice_checked(
+    t2_k: &[f64],
+    va: &[f64],
+    mrt: &[f64],
+    td_k: &[f64],
+) -> Result<Vec<f64>, ThermofeelError> {
+    check_same_len(t2_k.len(), &[va.len(), mrt.len(), td_k.len()])?;
+    Ok(calculate_utci_slice(t2_k, va, mrt, td_k))
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) for each unmasked element of a set of slices.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// Where `mask` is `true` for each element to skip.
+///
+/// The return value is UTCI in Kelvin for each element. Masked elements, and elements where any
+/// input is NaN, are NaN.
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+pub fn calculate_utci_slice_masked(
+    t2_k: &[f64],
+    va: &[f64],
+    mrt: &[f64],
+    td_k: &[f64],
+    mask: &[bool],
+) -> Vec<f64> {
+    assert_same_len(
+        t2_k.len(),
+        &[
+            ("va", va.len()),
+            ("mrt", mrt.len()),
+            ("td_k", td_k.len()),
+            ("mask", mask.len()),
+        ],
+    );
+
+    (0..t2_k.len())
+        .map(|i| {
+            if mask[i] {
+                f64::NAN
+            } else {
+                calculate_utci_or_nan(t2_k[i], va[i], mrt[i], td_k[i])
+            }
+        })
+        .collect()
+}
+
+/// Calculates the relative humidity percentage for each element of a pair of slices.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is [`calculate_relative_humidity_percent`] of each pair of elements. NaN in
+/// either input gives NaN.
+///
+/// # Panics
+///
+/// Panics if the slices do not have the same length.
+pub fn calculate_relative_humidity_percent_slice(t2_k: &[f64], td_k: &[f64]) -> Vec<f64> {
+    assert_same_len(t2_k.len(), &[("td_k", td_k.len())]);
+
+    t2_k.iter()
+        .zip(td_k)
+        .map(|(&t2_k, &td_k)| calculate_relative_humidity_percent(t2_k, td_k))
+        .collect()
+}
+
+/// Calculates the dew point temperature for each element of a pair of slices.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is [`calculate_dew_point_from_relative_humidity`] in Kelvin of each pair of
+/// elements. NaN in either input gives NaN.
+///
+/// # Panics
+///
+/// Panics if the slices do not have the same length.
+pub fn calculate_dew_point_from_relative_humidity_slice(rh: &[f64], t2_k: &[f64]) -> Vec<f64> {
+    assert_same_len(t2_k.len(), &[("rh", rh.len())]);
+
+    rh.iter()
+        .zip(t2_k)
+        .map(|(&rh, &t2_k)| calculate_dew_point_from_relative_humidity(rh, t2_k))
+        .collect()
+}
+
+/// Extension trait for computing UTCI lazily over an iterator of inputs.
+///
+/// This is implemented for any iterator of `(t2_k, va, mrt, td_k)` tuples, with the same units as
+/// [`calculate_utci_slice`].
+pub trait UtciIteratorExt: Iterator<Item = (f64, f64, f64, f64)> + Sized {
+    /// Maps each `(t2_k, va, mrt, td_k)` tuple to UTCI in Kelvin, without allocating.
+    ///
+    /// Elements where any input is NaN are NaN.
+    fn map_utci(self) -> MapUtci<Self> {
+        MapUtci { iter: self }
+    }
+}
+
+impl<I: Iterator<Item = (f64, f64, f64, f64)>> UtciIteratorExt for I {}
+
+/// Iterator returned by [`UtciIteratorExt::map_utci`].
+#[derive(Clone, Debug)]
+pub struct MapUtci<I> {
+    iter: I,
+}
+
+impl<I: Iterator<Item = (f64, f64, f64, f64)>> Iterator for MapUtci<I> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        self.iter
+            .next()
+            .map(|(t2_k, va, mrt, td_k)| calculate_utci_or_nan(t2_k, va, mrt, td_k))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Radiation fields for each element of a [`MeteoColumns`], in the units of
+/// [`calculate_mean_radiant_temperature`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RadiationColumns {
+    /// Surface solar radiation downwards in W m-2.
+    pub ssrd: Vec<f64>,
+    /// Surface net solar radiation in W m-2.
+    pub ssr: Vec<f64>,
+    /// Direct solar radiation in W m-2.
+    pub dsrp: Vec<f64>,
+    /// Surface thermal radiation downwards in W m-2.
+    pub strd: Vec<f64>,
+    /// Total sky direct solar radiation at surface in W m-2.
+    pub fdir: Vec<f64>,
+    /// Surface net thermal radiation in W m-2.
+    pub strr: Vec<f64>,
+    /// Cosine of the solar zenith angle (dimensionless).
+    pub cossza: Vec<f64>,
+}
+
+/// Meteorological inputs for many points, stored as one column per variable.
+///
+/// Every column, including those in `radiation`, must have the same length as `t2_k`, with element
+/// `i` of each column describing the same point. The `compute_*` methods panic if this invariant
+/// does not hold.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeteoColumns {
+    /// 2m temperature in Kelvin.
+    pub t2_k: Vec<f64>,
+    /// 2m dew point temperature in Kelvin.
+    pub td_k: Vec<f64>,
+    /// Wind speed at 10 meters in m/s.
+    pub va: Vec<f64>,
+    /// Mean radiant temperature in Kelvin.
+    pub mrt: Vec<f64>,
+    /// Optional radiation fields, needed by [`MeteoColumns::compute_mean_radiant_temperature`].
+    pub radiation: Option<RadiationColumns>,
+}
+
+impl MeteoColumns {
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.t2_k.len()
+    }
+
+    /// Returns `true` if there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.t2_k.is_empty()
+    }
+
+    pub(crate) fn assert_lengths(&self) {
+        assert_same_len(
+            self.len(),
+            &[
+                ("td_k", self.td_k.len()),
+                ("va", self.va.len()),
+                ("mrt", self.mrt.len()),
+            ],
+        );
+    }
+
+    /// Calculates the Universal Thermal Climate Index (UTCI) in Kelvin for each point, as
+    /// [`calculate_utci_slice`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn compute_utci(&self) -> Vec<f64> {
+        calculate_utci_slice(&self.t2_k, &self.va, &self.mrt, &self.td_k)
+    }
+
+    /// Calculates Wet Bulb Globe Temperature (WBGT) in Kelvin for each point, as [`calculate_wbgt`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn compute_wbgt(&self) -> Vec<f64> {
+        self.assert_lengths();
+        (0..self.len())
+            .map(|i| calculate_wbgt(self.t2_k[i], self.mrt[i], self.va[i], self.td_k[i]))
+            .collect()
+    }
+
+    /// Calculates Humidex in Kelvin for each point, as [`calculate_humidex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn compute_humidex(&self) -> Vec<f64> {
+        self.assert_lengths();
+        (0..self.len())
+            .map(|i| calculate_humidex(self.t2_k[i], self.td_k[i]))
+            .collect()
+    }
+
+    /// Calculates Wet Bulb Temperature in Kelvin for each point, as [`calculate_wbt`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn compute_wbt(&self) -> Vec<f64> {
+        self.assert_lengths();
+        (0..self.len())
+            .map(|i| {
+                let rh = calculate_relative_humidity_percent(self.t2_k[i], self.td_k[i]);
+                calculate_wbt(self.t2_k[i], rh)
+            })
+            .collect()
+    }
+
+    /// Calculates Mean Radiant Temperature (MRT) in Kelvin for each point from the radiation
+    /// columns, as [`calculate_mean_radiant_temperature`].
+    ///
+    /// The return value is `None` if there are no radiation columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radiation columns do not all have the same length as `t2_k`.
+    pub fn compute_mean_radiant_temperature(&self) -> Option<Vec<f64>> {
+        let r = self.radiation.as_ref()?;
+        assert_same_len(
+            self.len(),
+            &[
+                ("ssrd", r.ssrd.len()),
+                ("ssr", r.ssr.len()),
+                ("dsrp", r.dsrp.len()),
+                ("strd", r.strd.len()),
+                ("fdir", r.fdir.len()),
+                ("strr", r.strr.len()),
+                ("cossza", r.cossza.len()),
+            ],
+        );
+
+        Some(
+            (0..self.len())
+                .map(|i| {
+                    calculate_mean_radiant_temperature(
+                        r.ssrd[i],
+                        r.ssr[i],
+                        r.dsrp[i],
+                        r.strd[i],
+                        r.fdir[i],
+                        r.strr[i],
+                        r.cossza[i],
+                    )
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{AllIndices, calculate_all, kelvin_to_celsius, kelvin_to_fahrenheit};
+
+/// Runs `classify` on `value`, or returns `None` if `value` is NaN.
+///
+/// Every classifier goes through this, so missing data is reported as `None` instead of falling
+/// through every comparison into the last, most severe band.
+fn classify_unless_nan<T>(value: f64, classify: impl FnOnce(f64) -> T) -> Option<T> {
+    if value.is_nan() {
+        None
+    } else {
+        Some(classify(value))
+    }
+}
+
+/// Thermal sensation bands for apparent temperature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApparentTemperatureCategory {
+    /// Below 0°C.
+    VeryCold,
+    /// From 0°C up to 10°C.
+    Cold,
+    /// From 10°C up to 18°C.
+    Cool,
+    /// From 18°C up to 26°C.
+    Comfortable,
+    /// From 26°C up to 32°C.
+    Warm,
+    /// From 32°C up to 40°C.
+    Hot,
+    /// 40°C and above.
+    Extreme,
+}
+
+/// Classifies an apparent temperature into a thermal sensation band.
+///
+/// Where `at_k` is the apparent temperature in Kelvin, as from [`crate::calculate_apparent_temperature`].
+///
+/// The return value is the [`ApparentTemperatureCategory`] containing `at_k`, or `None` if it is
+/// NaN. Each band includes its lower bound.
+///
+/// The Bureau of Meteorology publishes apparent temperature without official bands, so the thresholds
+/// here are a pragmatic choice: an 18°C to 26°C comfortable range, widened in steps to 0°C at the
+/// cold end and 40°C at the hot end.
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/](http://www.bom.gov.au/info/thermal_stress/)
+pub fn classify_apparent_temperature(at_k: f64) -> Option<ApparentTemperatureCategory> {
+    classify_unless_nan(at_k, |at_k| {
+        let at_c = kelvin_to_celsius(at_k);
+
+        if at_c < 0.0 {
+            ApparentTemperatureCategory::VeryCold
+        } else if at_c < 10.0 {
+            ApparentTemperatureCategory::Cold
+        } else if at_c < 18.0 {
+            ApparentTemperatureCategory::Cool
+        } else if at_c < 26.0 {
+            ApparentTemperatureCategory::Comfortable
+        } else if at_c < 32.0 {
+            ApparentTemperatureCategory::Warm
+        } else if at_c < 40.0 {
+            ApparentTemperatureCategory::Hot
+        } else {
+            ApparentTemperatureCategory::Extreme
+        }
+    })
+}
+
+/// Thermal stress categories of the Universal Thermal Climate Index (UTCI).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UtciCategory {
+    /// Below -40°C.
+    ExtremeColdStress,
+    /// From -40°C up to -27°C.
+    VeryStrongColdStress,
+    /// From -27°C up to -13°C.
+    StrongColdStress,
+    /// From -13°C up to 0°C.
+    ModerateColdStress,
+    /// From 0°C up to 9°C.
+    SlightColdStress,
+    /// From 9°C up to 26°C.
+    NoThermalStress,
+    /// From 26°C up to 32°C.
+    ModerateHeatStress,
+    /// From 32°C up to 38°C.
+    StrongHeatStress,
+    /// From 38°C up to 46°C.
+    VeryStrongHeatStress,
+    /// 46°C and above.
+    ExtremeHeatStress,
+}
+
+/// Classifies a UTCI value into a thermal stress category.
+///
+/// Where `utci_k` is the UTCI in Kelvin, as from [`crate::calculate_utci`].
+///
+/// The return value is the [`UtciCategory`] containing `utci_k`, or `None` if it is NaN. Each band
+/// includes its lower bound.
+///
+/// Reference: Blazejczyk et al. (2013) [https://doi.org/10.7163/GPol.2013.1](https://doi.org/10.7163/GPol.2013.1)
+pub fn classify_utci(utci_k: f64) -> Option<UtciCategory> {
+    classify_unless_nan(utci_k, |utci_k| {
+        let utci_c = kelvin_to_celsius(utci_k);
+
+        if utci_c < -40.0 {
+            UtciCategory::ExtremeColdStress
+        } else if utci_c < -27.0 {
+            UtciCategory::VeryStrongColdStress
+        } else if utci_c < -13.0 {
+            UtciCategory::StrongColdStress
+        } else if utci_c < 0.0 {
+            UtciCategory::ModerateColdStress
+        } else if utci_c < 9.0 {
+            UtciCategory::SlightColdStress
+        } else if utci_c < 26.0 {
+            UtciCategory::NoThermalStress
+        } else if utci_c < 32.0 {
+            UtciCategory::ModerateHeatStress
+        } else if utci_c < 38.0 {
+            UtciCategory::StrongHeatStress
+        } else if utci_c < 46.0 {
+            UtciCategory::VeryStrongHeatStress
+        } else {
+            UtciCategory::ExtremeHeatStress
+        }
+    })
+}
+
+/// US National Weather Service heat index risk levels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeatIndexCategory {
+    /// Below 80°F (26.7°C).
+    NoRisk,
+    /// From 80°F (26.7°C) up to 90°F (32.2°C): fatigue possible with prolonged exposure.
+    Caution,
+    /// From 90°F (32.2°C) up to 103°F (39.4°C): heat cramps and exhaustion possible.
+    ExtremeCaution,
+    /// From 103°F (39.4°C) up to 125°F (51.7°C): heat exhaustion likely.
+    Danger,
+    /// 125°F (51.7°C) and above: heat stroke highly likely.
+    ExtremeDanger,
+}
+
+/// Classifies a heat index into a risk level.
+///
+/// Where `hi_k` is the heat index in Kelvin, as from [`crate::calculate_heat_index_adjusted`].
+///
+/// The return value is the [`HeatIndexCategory`] containing `hi_k`, or `None` if it is NaN. Each
+/// band includes its lower bound.
+///
+/// Reference: [https://www.weather.gov/ama/heatindex](https://www.weather.gov/ama/heatindex)
+pub fn classify_heat_index(hi_k: f64) -> Option<HeatIndexCategory> {
+    classify_unless_nan(hi_k, |hi_k| {
+        let hi_f = kelvin_to_fahrenheit(hi_k);
+
+        if hi_f < 80.0 {
+            HeatIndexCategory::NoRisk
+        } else if hi_f < 90.0 {
+            HeatIndexCategory::Caution
+        } else if hi_f < 103.0 {
+            HeatIndexCategory::ExtremeCaution
+        } else if hi_f < 125.0 {
+            HeatIndexCategory::Danger
+        } else {
+            HeatIndexCategory::ExtremeDanger
+        }
+    })
+}
+
+/// Environment Canada Humidex comfort levels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HumidexCategory {
+    /// Below 30.
+    NoDiscomfort,
+    /// From 30 up to 40.
+    SomeDiscomfort,
+    /// From 40 up to and including 45: avoid exertion.
+    GreatDiscomfort,
+    /// Above 45: heat stroke possible.
+    Dangerous,
+}
+
+/// Classifies a Humidex value into a comfort level.
+///
+/// Where `humidex_k` is the Humidex in Kelvin, as from [`crate::calculate_humidex`].
+///
+/// The return value is the [`HumidexCategory`] containing `humidex_k`, compared in Celsius, or
+/// `None` if it is NaN. The published bands are whole numbers ("30 to 39", "40 to 45", "above 45"),
+/// read here as `[30, 40)`, `[40, 45]` and above 45.
+///
+/// See also: [https://web.archive.org/web/20130627223738/http://climate.weatheroffice.gc.ca/prods_servs/normals_documentation_e.html](https://web.archive.org/web/20130627223738/http://climate.weatheroffice.gc.ca/prods_servs/normals_documentation_e.html)
+pub fn classify_humidex(humidex_k: f64) -> Option<HumidexCategory> {
+    classify_unless_nan(humidex_k, |humidex_k| {
+        let humidex_c = kelvin_to_celsius(humidex_k);
+
+        if humidex_c < 30.0 {
+            HumidexCategory::NoDiscomfort
+        } else if humidex_c < 40.0 {
+            HumidexCategory::SomeDiscomfort
+        } else if humidex_c <= 45.0 {
+            HumidexCategory::GreatDiscomfort
+        } else {
+            HumidexCategory::Dangerous
+        }
+    })
+}
+
+/// Livestock Weather Safety Index heat stress levels for cattle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CattleHeatStress {
+    /// Below 75.
+    Normal,
+    /// From 75 up to 79: reduced feed intake, watch the herd.
+    Alert,
+    /// From 79 up to 84: avoid handling and transport.
+    Danger,
+    /// 84 and above: losses likely without intervention.
+    Emergency,
+}
+
+/// Classifies a cattle Temperature-Humidity Index into a heat stress level.
+///
+/// Where `thi` is the THI (dimensionless), as from [`crate::calculate_cattle_thi`].
+///
+/// The return value is the [`CattleHeatStress`] containing `thi`, or `None` if it is NaN. The
+/// published bands are whole numbers ("74 or less", "75 to 78", "79 to 83", "84 or more"), read
+/// here as `[75, 79)`, `[79, 84)` and from 84. These thresholds are for cattle only.
+///
+/// Reference: Livestock Conservation Institute (1970), Patterns of transit losses, Omaha, Nebraska
+pub fn classify_cattle_thi(thi: f64) -> Option<CattleHeatStress> {
+    classify_unless_nan(thi, |thi| {
+        if thi < 75.0 {
+            CattleHeatStress::Normal
+        } else if thi < 79.0 {
+            CattleHeatStress::Alert
+        } else if thi < 84.0 {
+            CattleHeatStress::Danger
+        } else {
+            CattleHeatStress::Emergency
+        }
+    })
+}
+
+/// Environment Canada wind chill frostbite risk levels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindChillRisk {
+    /// Above -10: low risk of frostbite.
+    Low,
+    /// From -10 down to above -28: frostbite unlikely with normal exposure.
+    Moderate,
+    /// From -28 down to above -40: exposed skin freezes in 10 to 30 minutes.
+    High,
+    /// From -40 down to above -48: exposed skin freezes in 5 to 10 minutes.
+    VeryHigh,
+    /// From -48 down to above -55: exposed skin freezes in 2 to 5 minutes.
+    Severe,
+    /// -55 and below: exposed skin freezes in under 2 minutes.
+    Extreme,
+}
+
+/// Classifies a wind chill into a frostbite risk level.
+///
+/// Where `wind_chill_k` is the wind chill in Kelvin, as from [`crate::calculate_wind_chill`].
+///
+/// The return value is the [`WindChillRisk`] containing `wind_chill_k`, compared in Celsius, or
+/// `None` if it is NaN. Each band includes its colder bound, so -10 is [`WindChillRisk::Moderate`]
+/// as in the published "-10 to -27" band.
+///
+/// Reference: Environment and Climate Change Canada, wind chill hazards and risk of frostbite
+pub fn classify_wind_chill(wind_chill_k: f64) -> Option<WindChillRisk> {
+    classify_unless_nan(wind_chill_k, |wind_chill_k| {
+        let wind_chill_c = kelvin_to_celsius(wind_chill_k);
+
+        if wind_chill_c > -10.0 {
+            WindChillRisk::Low
+        } else if wind_chill_c > -28.0 {
+            WindChillRisk::Moderate
+        } else if wind_chill_c > -40.0 {
+            WindChillRisk::High
+        } else if wind_chill_c > -48.0 {
+            WindChillRisk::VeryHigh
+        } else if wind_chill_c > -55.0 {
+            WindChillRisk::Severe
+        } else {
+            WindChillRisk::Extreme
+        }
+    })
+}
+
+/// Thermal comfort across several indices, for display.
+///
+/// With the `serde` feature this implements `Serialize` and `Deserialize`, using `null` for
+/// categories whose index does not apply or is NaN.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComfortSummary {
+    /// [`classify_utci`] of [`AllIndices::utci`], or `None` if it is NaN.
+    pub utci: Option<UtciCategory>,
+    /// [`classify_heat_index`] of [`AllIndices::heat_index`], or `None` at or below 20°C or if it is
+    /// NaN.
+    pub heat_index: Option<HeatIndexCategory>,
+    /// [`classify_humidex`] of [`AllIndices::humidex`], or `None` if it is NaN.
+    pub humidex: Option<HumidexCategory>,
+    /// [`classify_wind_chill`] of [`AllIndices::wind_chill`], or `None` outside -50°C to 5°C and
+    /// 5km/h to 80km/h or if it is NaN.
+    pub wind_chill: Option<WindChillRisk>,
+}
+
+impl ComfortSummary {
+    /// Classifies indices that have already been calculated.
+    pub fn from_indices(indices: &AllIndices) -> Self {
+        Self {
+            utci: classify_utci(indices.utci),
+            heat_index: indices.heat_index.and_then(classify_heat_index),
+            humidex: classify_humidex(indices.humidex),
+            wind_chill: indices.wind_chill.and_then(classify_wind_chill),
+        }
+    }
+}
+
+/// Summarises thermal comfort from a single set of meteorological inputs.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// The return value is a [`ComfortSummary`] of the indices from [`calculate_all`]. The heat index
+/// there is the simplified form, which runs slightly different to the NWS regression the
+/// [`HeatIndexCategory`] bands were set for.
+pub fn calculate_comfort_summary(t2_k: f64, td_k: f64, va: f64, mrt: f64) -> ComfortSummary {
+    ComfortSummary::from_indices(&calculate_all(t2_k, td_k, va, mrt))
+}
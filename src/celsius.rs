@@ -0,0 +1,146 @@
+//! Celsius versions of the thermal indices.
+//!
+//! These wrappers exist purely for ergonomics when working with data in Celsius, such as ground
+//! station observations. Each converts its temperature inputs to Kelvin, calls the function of the
+//! same name at the crate root and converts the result back to Celsius. Wind speeds, humidities and
+//! pressures are unchanged.
+
+use crate::{celsius_to_kelvin, kelvin_to_celsius};
+
+/// Calculates relative humidity in percent from temperature and dew point temperature in Celsius.
+///
+/// See [`crate::calculate_relative_humidity_percent`].
+pub fn calculate_relative_humidity_percent(t2_c: f64, td_c: f64) -> f64 {
+    crate::calculate_relative_humidity_percent(celsius_to_kelvin(t2_c), celsius_to_kelvin(td_c))
+}
+
+/// Calculates dew point temperature in Celsius from relative humidity and temperature in Celsius.
+///
+/// See [`crate::calculate_dew_point_from_relative_humidity`].
+pub fn calculate_dew_point_from_relative_humidity(rh: f64, t2_c: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_dew_point_from_relative_humidity(
+        rh,
+        celsius_to_kelvin(t2_c),
+    ))
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) in Celsius.
+///
+/// See [`crate::calculate_utci`].
+pub fn calculate_utci(
+    t2_c: f64,
+    va: f64,
+    mrt_c: f64,
+    td_c: Option<f64>,
+    eh_pa: Option<f64>,
+) -> f64 {
+    kelvin_to_celsius(crate::calculate_utci(
+        celsius_to_kelvin(t2_c),
+        va,
+        celsius_to_kelvin(mrt_c),
+        td_c.map(celsius_to_kelvin),
+        eh_pa,
+    ))
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) using a simplified algorithm in Celsius.
+///
+/// See [`crate::calculate_wbgt_simple`].
+pub fn calculate_wbgt_simple(t2_c: f64, rh: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_wbgt_simple(celsius_to_kelvin(t2_c), rh))
+}
+
+/// Calculates Wet Bulb Temperature in Celsius.
+///
+/// See [`crate::calculate_wbt`].
+pub fn calculate_wbt(t2_c: f64, rh: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_wbt(celsius_to_kelvin(t2_c), rh))
+}
+
+/// Calculates Globe Temperature in Celsius.
+///
+/// See [`crate::calculate_bgt`].
+pub fn calculate_bgt(t2_c: f64, mrt_c: f64, va: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_bgt(
+        celsius_to_kelvin(t2_c),
+        celsius_to_kelvin(mrt_c),
+        va,
+    ))
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) in Celsius.
+///
+/// See [`crate::calculate_wbgt`].
+pub fn calculate_wbgt(t2_c: f64, mrt_c: f64, va: f64, td_c: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_wbgt(
+        celsius_to_kelvin(t2_c),
+        celsius_to_kelvin(mrt_c),
+        va,
+        celsius_to_kelvin(td_c),
+    ))
+}
+
+/// Calculates Mean Radiant Temperature from Globe Temperature in Celsius.
+///
+/// See [`crate::calculate_mrt_from_bgt`].
+pub fn calculate_mrt_from_bgt(t2_c: f64, bgt_c: f64, va: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_mrt_from_bgt(
+        celsius_to_kelvin(t2_c),
+        celsius_to_kelvin(bgt_c),
+        va,
+    ))
+}
+
+/// Calculates Humidex in Celsius.
+///
+/// See [`crate::calculate_humidex`].
+pub fn calculate_humidex(t2_c: f64, td_c: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_humidex(
+        celsius_to_kelvin(t2_c),
+        celsius_to_kelvin(td_c),
+    ))
+}
+
+/// Calculates Normal Effective Temperature (NET) in Celsius.
+///
+/// See [`crate::calculate_normal_effective_temperature`].
+pub fn calculate_normal_effective_temperature(t2_c: f64, va: f64, rh: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_normal_effective_temperature(
+        celsius_to_kelvin(t2_c),
+        va,
+        rh,
+    ))
+}
+
+/// Calculates Apparent Temperature in Celsius.
+///
+/// See [`crate::calculate_apparent_temperature`].
+pub fn calculate_apparent_temperature(t2_c: f64, va: f64, rh: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_apparent_temperature(
+        celsius_to_kelvin(t2_c),
+        va,
+        rh,
+    ))
+}
+
+/// Calculates Wind Chill in Celsius.
+///
+/// See [`crate::calculate_wind_chill`].
+pub fn calculate_wind_chill(t2_c: f64, va: f64) -> f64 {
+    kelvin_to_celsius(crate::calculate_wind_chill(celsius_to_kelvin(t2_c), va))
+}
+
+/// Calculates Heat Index using a simplified method in Celsius.
+///
+/// See [`crate::calculate_heat_index_simplified`].
+pub fn calculate_heat_index_simplified(t2_c: f64, rh: f64) -> Option<f64> {
+    crate::calculate_heat_index_simplified(celsius_to_kelvin(t2_c), rh).map(kelvin_to_celsius)
+}
+
+/// Calculates Heat Index with adjustments in Celsius.
+///
+/// See [`crate::calculate_heat_index_adjusted`].
+pub fn calculate_heat_index_adjusted(t2_c: f64, td_c: f64) -> Option<f64> {
+    crate::calculate_heat_index_adjusted(celsius_to_kelvin(t2_c), celsius_to_kelvin(td_c))
+        .map(kelvin_to_celsius)
+}
@@ -0,0 +1,57 @@
+//! Physical constants used by the thermal indices.
+
+/// The Stefan-Boltzmann constant in W m-2 K-4.
+pub const STEFAN_BOLTZMANN: f64 = 5.67e-8;
+
+/// The temperature of 0°C in Kelvin.
+pub const ZERO_CELSIUS_K: f64 = 273.15;
+
+/// The triple point of water in Kelvin (0.01°C, at 611.657 Pa).
+pub const TRIPLE_POINT_K: f64 = 273.16;
+
+/// The ratio of the molar masses of water vapour and dry air (dimensionless).
+pub const MOLAR_MASS_RATIO: f64 = 0.622;
+
+/// The latent heat of vaporization of water divided by the gas constant for water vapour, in Kelvin,
+/// as used by the Clausius-Clapeyron vapour pressure in Humidex.
+pub const LATENT_HEAT_OVER_VAPOUR_GAS_CONSTANT_K: f64 = 5417.7530;
+
+/// Standard sea level air pressure in hPa.
+pub const STANDARD_PRESSURE_HPA: f64 = 1013.25;
+
+/// The psychrometer coefficient for an aspirated (Assmann) psychrometer in K-1.
+pub const PSYCHROMETER_COEFFICIENT: f64 = 6.53e-4;
+
+/// The specific heat capacity of dry air at constant pressure in J kg-1 K-1.
+pub const SPECIFIC_HEAT_DRY_AIR: f64 = 1005.7;
+
+/// The total solar irradiance at the mean Earth-Sun distance in W m-2.
+pub const SOLAR_CONSTANT: f64 = 1361.0;
+
+/// The emissivity of the standard black globe thermometer (dimensionless).
+///
+/// ISO 7726 specifies a thin hollow copper sphere painted matt black, whose emissivity is about
+/// 0.95. This is the value assumed by [`crate::calculate_bgt`] and [`crate::calculate_mrt_from_bgt`].
+pub const STANDARD_GLOBE_EMISSIVITY: f64 = 0.95;
+
+/// The diameter of the standard black globe thermometer in meters.
+///
+/// This is the 150 mm (about 6 inch) Vernon globe specified by ISO 7726 and the WMO, and used in
+/// WBGT measurement. Smaller globes respond faster but exchange heat by convection differently, so
+/// they need [`crate::calculate_bgt_custom`] or [`crate::calculate_mrt_from_globe`].
+pub const STANDARD_GLOBE_DIAMETER_M: f64 = 0.15;
+
+/// The forced convection coefficient of a globe thermometer divided by [`STEFAN_BOLTZMANN`], in
+/// K3 (m/s)^-0.6 m^0.4.
+///
+/// ISO 7726 gives the convective heat transfer coefficient of a globe of diameter `D` in wind speed
+/// `v` as `6.3 v^0.6 / D^0.4` W m-2 K-1, and its mean radiant temperature formula as `1.1e8` times
+/// `v^0.6 / D^0.4` over the emissivity. The crate uses the second form everywhere, so the
+/// coefficient is `1.1e8 σ`, about 6.24.
+pub const GLOBE_FORCED_CONVECTION_OVER_STEFAN_BOLTZMANN: f64 = 1.1e8;
+
+/// The surface roughness length of the logarithmic wind profile in meters.
+///
+/// This is the 0.01 m of open, flat terrain with short grass that [`crate::scale_windspeed`] assumes
+/// when scaling the 10m wind speed to other heights. The profile gives zero wind at this height.
+pub const ROUGHNESS_LENGTH_M: f64 = 0.01;
@@ -0,0 +1,88 @@
+use crate::constants::{MOLAR_MASS_RATIO, ZERO_CELSIUS_K};
+use crate::mixing_ratio_from_vapour_pressure;
+
+const KAPPA: f64 = 0.2854; // Rd / cp for dry air (dimensionless)
+const LAMBDA: f64 = 1.0 / KAPPA;
+const P0: f64 = 1000.0; // reference pressure [hPa]
+
+/// Bolton (1980) saturation vapour pressure over water in hPa.
+fn bolton_es(t_k: f64) -> f64 {
+    let t_c = t_k - ZERO_CELSIUS_K;
+    6.112 * (17.67 * t_c / (t_c + 243.5)).exp()
+}
+
+/// Derivative of `ln(bolton_es)` with respect to temperature in K-1.
+fn bolton_dlnes_dt(t_k: f64) -> f64 {
+    let t_c = t_k - ZERO_CELSIUS_K;
+    17.67 * 243.5 / ((t_c + 243.5) * (t_c + 243.5))
+}
+
+/// Calculates equivalent potential temperature.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the equivalent potential temperature in Kelvin: the potential temperature of
+/// the parcel after lifting it pseudoadiabatically until all its water vapour has condensed. It is
+/// conserved along pseudoadiabats, which is what makes it useful for wet bulb calculations. Vapour
+/// pressure uses Bolton's saturation formula, not [`crate::calculate_saturation_vapour_pressure`],
+/// since the fit was made with it; the mixing ratio is then found as in
+/// [`crate::calculate_mixing_ratio`].
+///
+/// Reference: Bolton (1980), equation 39 [https://doi.org/10.1175/1520-0493(1980)108<1046:TCOEPT>2.0.CO;2](https://doi.org/10.1175/1520-0493(1980)108<1046:TCOEPT>2.0.CO;2)
+pub fn calculate_equivalent_potential_temperature(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let e = bolton_es(t2_k) * rh / 100.0;
+    let r = mixing_ratio_from_vapour_pressure(e, pressure_hpa);
+
+    // Temperature at the lifting condensation level, Bolton (1980) equation 22.
+    let t_l = 1.0 / (1.0 / (t2_k - 55.0) - (rh / 100.0).ln() / 2840.0) + 55.0;
+    let theta_dl = t2_k * (P0 / (pressure_hpa - e)).powf(KAPPA) * (t2_k / t_l).powf(0.28 * r);
+    theta_dl * ((3036.0 / t_l - 1.78) * r * (1.0 + 0.448 * r)).exp()
+}
+
+/// `ln` of the equivalent potential temperature of saturated air, and its temperature derivative.
+fn ln_saturated_theta_e(t_k: f64, pressure_hpa: f64) -> (f64, f64) {
+    let e = bolton_es(t_k);
+    let de = e * bolton_dlnes_dt(t_k);
+    let r = mixing_ratio_from_vapour_pressure(e, pressure_hpa);
+    let dr = MOLAR_MASS_RATIO * de * pressure_hpa / ((pressure_hpa - e) * (pressure_hpa - e));
+
+    let g = 3036.0 / t_k - 1.78;
+    let value = t_k.ln() + KAPPA * (P0 / (pressure_hpa - e)).ln() + g * r * (1.0 + 0.448 * r);
+    let derivative = 1.0 / t_k + KAPPA * de / (pressure_hpa - e)
+        - 3036.0 / (t_k * t_k) * r * (1.0 + 0.448 * r)
+        + g * (1.0 + 0.896 * r) * dr;
+    (value, derivative)
+}
+
+/// Davies-Jones (2008) wet bulb temperature in Kelvin: the first guess of section 4 followed by one
+/// Newton step on the saturated equivalent potential temperature.
+pub(crate) fn davies_jones_wet_bulb(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let theta_e = calculate_equivalent_potential_temperature(t2_k, rh, pressure_hpa);
+    let pi = (pressure_hpa / P0).powf(KAPPA);
+    let t_e = theta_e * pi;
+    let x = (ZERO_CELSIUS_K / t_e).powf(LAMBDA);
+
+    let k1 = -38.5 * pi * pi + 137.81 * pi - 53.737;
+    let k2 = -4.392 * pi * pi + 56.831 * pi - 0.384;
+    let d = 1.0 / (0.1859 * pressure_hpa / P0 + 0.6512);
+
+    let first_guess_c = if x > d {
+        let a = 2675.0;
+        let rs = mixing_ratio_from_vapour_pressure(bolton_es(t_e), pressure_hpa);
+        t_e - ZERO_CELSIUS_K - a * rs / (1.0 + a * rs * bolton_dlnes_dt(t_e))
+    } else if x >= 1.0 {
+        k1 - k2 * x
+    } else if x >= 0.4 {
+        (k1 - 1.21) - (k2 - 1.21) * x
+    } else {
+        (k1 - 2.66) - (k2 - 1.21) * x + 0.58 / x
+    };
+    let first_guess = first_guess_c + ZERO_CELSIUS_K;
+
+    let (value, derivative) = ln_saturated_theta_e(first_guess, pressure_hpa);
+    first_guess - (value - theta_e.ln()) / derivative
+}
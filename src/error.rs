@@ -0,0 +1,64 @@
+use std::fmt;
+
+/// The error type for the checked functions of this crate.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThermofeelError {
+    /// An input slice does not have the same length as the first input.
+    MismatchedSliceLengths {
+        /// The length of the first input.
+        expected: usize,
+        /// The length of the mismatched input.
+        got: usize,
+    },
+    /// An input lies outside the range the calculation is valid for. An unbounded side of the
+    /// range is infinite.
+    OutOfValidRange {
+        /// The name of the input, or of the expression of the inputs that was checked.
+        name: &'static str,
+        /// The rejected value.
+        value: f64,
+        /// The lowest valid value.
+        min: f64,
+        /// The highest valid value.
+        max: f64,
+    },
+    /// Neither a dew point temperature nor a water vapour pressure was given.
+    MissingHumidityInput,
+    /// A root finder was given a bracket over which the function does not change sign.
+    RootNotBracketed {
+        /// The lower end of the bracket.
+        lo: f64,
+        /// The upper end of the bracket.
+        hi: f64,
+    },
+    /// An iterative calculation did not converge.
+    NoConvergence {
+        /// The number of iterations made.
+        iterations: usize,
+    },
+}
+
+impl fmt::Display for ThermofeelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ThermofeelError::MismatchedSliceLengths { expected, got } => {
+                write!(f, "input has length {got} but expected length {expected}")
+            }
+            ThermofeelError::OutOfValidRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(f, "`{name}` = {value} is outside [{min}, {max}]"),
+            ThermofeelError::MissingHumidityInput => write!(f, "missing input ehPa or td_k"),
+            ThermofeelError::RootNotBracketed { lo, hi } => {
+                write!(f, "no sign change to bracket a root between {lo} and {hi}")
+            }
+            ThermofeelError::NoConvergence { iterations } => {
+                write!(f, "did not converge within {iterations} iterations")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThermofeelError {}
@@ -0,0 +1,158 @@
+//! C-compatible bindings, enabled with the `ffi` feature.
+//!
+//! Every function returns a status code and writes its result through the `out` pointer.
+//! Optional inputs are passed as NaN when not provided. When an index does not apply to its
+//! inputs, NaN is written to `out` and [`TF_NOT_APPLICABLE`] is returned.
+//!
+//! The matching C header is `include/thermofeel.h`, generated with `cbindgen`.
+
+use std::ffi::c_int;
+
+use crate::*;
+
+/// The result was written to `out`.
+pub const TF_OK: c_int = 0;
+/// The `out` pointer was null; nothing was written.
+pub const TF_NULL_POINTER: c_int = 1;
+/// A required input was not provided; NaN was written to `out`.
+pub const TF_MISSING_INPUT: c_int = 2;
+/// The index does not apply to the inputs; NaN was written to `out`.
+pub const TF_NOT_APPLICABLE: c_int = 3;
+
+fn optional(value: f64) -> Option<f64> {
+    if value.is_nan() { None } else { Some(value) }
+}
+
+unsafe fn write_result(out: *mut f64, value: Option<f64>, missing: c_int) -> c_int {
+    if out.is_null() {
+        return TF_NULL_POINTER;
+    }
+
+    // SAFETY: the caller guarantees that a non-null `out` is valid for writes.
+    unsafe { *out = value.unwrap_or(f64::NAN) };
+
+    if value.is_some() { TF_OK } else { missing }
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) in Kelvin, see [`calculate_utci`].
+///
+/// `td_k` and `eh_pa` are optional and may be NaN, but at least one must be provided,
+/// otherwise [`TF_MISSING_INPUT`] is returned.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_utci(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    td_k: f64,
+    eh_pa: f64,
+    out: *mut f64,
+) -> c_int {
+    let (td_k, eh_pa) = (optional(td_k), optional(eh_pa));
+    let utci = if td_k.is_some() || eh_pa.is_some() {
+        Some(calculate_utci(t2_k, va, mrt, td_k, eh_pa))
+    } else {
+        None
+    };
+
+    unsafe { write_result(out, utci, TF_MISSING_INPUT) }
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) in Kelvin, see [`calculate_wbgt`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_wbgt(
+    t2_k: f64,
+    mrt: f64,
+    va: f64,
+    td_k: f64,
+    out: *mut f64,
+) -> c_int {
+    unsafe { write_result(out, Some(calculate_wbgt(t2_k, mrt, va, td_k)), TF_OK) }
+}
+
+/// Calculates the simplified Wet Bulb Globe Temperature in Kelvin, see [`calculate_wbgt_simple`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_wbgt_simple(t2_k: f64, rh: f64, out: *mut f64) -> c_int {
+    unsafe { write_result(out, Some(calculate_wbgt_simple(t2_k, rh)), TF_OK) }
+}
+
+/// Calculates Humidex in Kelvin, see [`calculate_humidex`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_humidex(t2_k: f64, td_k: f64, out: *mut f64) -> c_int {
+    unsafe { write_result(out, Some(calculate_humidex(t2_k, td_k)), TF_OK) }
+}
+
+/// Calculates the apparent temperature in Kelvin, see [`calculate_apparent_temperature`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_apparent_temperature(
+    t2_k: f64,
+    va: f64,
+    rh: f64,
+    out: *mut f64,
+) -> c_int {
+    let at = calculate_apparent_temperature(t2_k, va, rh);
+    unsafe { write_result(out, Some(at), TF_OK) }
+}
+
+/// Calculates the wind chill in Kelvin, see [`calculate_wind_chill`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_wind_chill(t2_k: f64, va: f64, out: *mut f64) -> c_int {
+    unsafe { write_result(out, Some(calculate_wind_chill(t2_k, va)), TF_OK) }
+}
+
+/// Calculates the simplified heat index in Kelvin, see [`calculate_heat_index_simplified`].
+///
+/// Returns [`TF_NOT_APPLICABLE`] when the temperature is too low.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_heat_index_simplified(
+    t2_k: f64,
+    rh: f64,
+    out: *mut f64,
+) -> c_int {
+    let hi = calculate_heat_index_simplified(t2_k, rh);
+    unsafe { write_result(out, hi, TF_NOT_APPLICABLE) }
+}
+
+/// Calculates the adjusted heat index in Kelvin, see [`calculate_heat_index_adjusted`].
+///
+/// Returns [`TF_NOT_APPLICABLE`] when the conditions are not met for calculation.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tf_calculate_heat_index_adjusted(
+    t2_k: f64,
+    td_k: f64,
+    out: *mut f64,
+) -> c_int {
+    let hi = calculate_heat_index_adjusted(t2_k, td_k);
+    unsafe { write_result(out, hi, TF_NOT_APPLICABLE) }
+}
@@ -1,13 +1,16 @@
+use crate::ThermofeelError;
+use crate::constants::ZERO_CELSIUS_K;
+
 pub fn celsius_to_kelvin(tc: f64) -> f64 {
-    tc + 273.15
+    tc + ZERO_CELSIUS_K
 }
 
 pub fn kelvin_to_celsius(tk: f64) -> f64 {
-    tk - 273.15
+    tk - ZERO_CELSIUS_K
 }
 
 pub fn kelvin_to_fahrenheit(tk: f64) -> f64 {
-    (tk - 273.15) * 9.0 / 5.0 + 32.0
+    (tk - ZERO_CELSIUS_K) * 9.0 / 5.0 + 32.0
 }
 
 pub fn fahrenheit_to_celsius(tf: f64) -> f64 {
@@ -17,3 +20,112 @@ pub fn fahrenheit_to_celsius(tf: f64) -> f64 {
 pub fn fahrenheit_to_kelvin(tf: f64) -> f64 {
     (tf + 459.67) * 5.0 / 9.0
 }
+
+pub fn kelvin_to_rankine(tk: f64) -> f64 {
+    tk * 9.0 / 5.0
+}
+
+pub fn rankine_to_kelvin(tr: f64) -> f64 {
+    tr * 5.0 / 9.0
+}
+
+pub fn fahrenheit_to_rankine(tf: f64) -> f64 {
+    tf + 459.67
+}
+
+pub fn rankine_to_fahrenheit(tr: f64) -> f64 {
+    tr - 459.67
+}
+
+/// Returns `tk` if it is at or above absolute zero, otherwise an error naming the input `value`,
+/// whose lowest valid value is `min`.
+fn check_absolute_zero(
+    tk: f64,
+    name: &'static str,
+    value: f64,
+    min: f64,
+) -> Result<f64, ThermofeelError> {
+    if tk >= 0.0 {
+        Ok(tk)
+    } else {
+        Err(ThermofeelError::OutOfValidRange {
+            name,
+            value,
+            min,
+            max: f64::INFINITY,
+        })
+    }
+}
+
+/// Converts Celsius to Kelvin, rejecting temperatures below absolute zero.
+///
+/// Where `tc` is the temperature in Celsius.
+///
+/// The return value is the temperature in Kelvin, as [`celsius_to_kelvin`], or
+/// [`ThermofeelError::OutOfValidRange`] if it would be negative, that is if `tc` is below
+/// -273.15°C. Absolute zero itself is accepted. NaN is rejected, so this suits validating input
+/// data; the infallible conversions pass both through unchanged.
+pub fn celsius_to_kelvin_checked(tc: f64) -> Result<f64, ThermofeelError> {
+    check_absolute_zero(celsius_to_kelvin(tc), "tc", tc, -ZERO_CELSIUS_K)
+}
+
+/// Converts Fahrenheit to Kelvin, rejecting temperatures below absolute zero.
+///
+/// Where `tf` is the temperature in Fahrenheit.
+///
+/// The return value is the temperature in Kelvin, as [`fahrenheit_to_kelvin`], or
+/// [`ThermofeelError::OutOfValidRange`] if it would be negative, that is if `tf` is below
+/// -459.67°F. As with [`celsius_to_kelvin_checked`], NaN is rejected.
+pub fn fahrenheit_to_kelvin_checked(tf: f64) -> Result<f64, ThermofeelError> {
+    check_absolute_zero(fahrenheit_to_kelvin(tf), "tf", tf, -459.67)
+}
+
+/// Rounds `value` to `decimals` decimal places, with ties rounded to even.
+///
+/// The return value is the multiple of `10^-decimals` nearest to `value`. Halfway cases go to the
+/// even neighbour, so `0.125` rounds to `0.12` and `0.375` to `0.38` at two decimals. Round
+/// half-to-even avoids the upward bias of rounding halves away from zero when many values are
+/// rounded and then aggregated. The tie is decided on `value * 10^decimals`, which is itself rounded
+/// to the nearest `f64`, so a decimal tie that is not exactly representable is usually still treated
+/// as one: `2.675` (stored as `2.67499999...`) rounds to `2.68`. This is not guaranteed, as with
+/// `0.285`, which rounds to `0.28`. Values too large to have a fractional part at this precision,
+/// infinities and NaN are returned unchanged.
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals.min(309) as i32);
+    let scaled = value * scale;
+    if !scaled.is_finite() || scaled.abs() >= 2f64.powi(52) {
+        return value;
+    }
+    scaled.round_ties_even() / scale
+}
+
+macro_rules! slice_conversions {
+    ($($scalar:ident => $slice:ident, $inplace:ident;)*) => {$(
+        #[doc = concat!("Applies [`", stringify!($scalar), "`] to every element of `values`, returning a new `Vec`.")]
+        pub fn $slice(values: &[f64]) -> Vec<f64> {
+            values.iter().map(|&v| $scalar(v)).collect()
+        }
+
+        #[doc = concat!("Applies [`", stringify!($scalar), "`] to every element of `values` in place.")]
+        ///
+        /// This overwrites the input without allocating, which suits large grids that are not needed
+        /// in their original unit afterwards. NaN stays NaN.
+        pub fn $inplace(values: &mut [f64]) {
+            for v in values.iter_mut() {
+                *v = $scalar(*v);
+            }
+        }
+    )*};
+}
+
+slice_conversions! {
+    celsius_to_kelvin => celsius_to_kelvin_slice, celsius_to_kelvin_inplace;
+    kelvin_to_celsius => kelvin_to_celsius_slice, kelvin_to_celsius_inplace;
+    kelvin_to_fahrenheit => kelvin_to_fahrenheit_slice, kelvin_to_fahrenheit_inplace;
+    fahrenheit_to_celsius => fahrenheit_to_celsius_slice, fahrenheit_to_celsius_inplace;
+    fahrenheit_to_kelvin => fahrenheit_to_kelvin_slice, fahrenheit_to_kelvin_inplace;
+    kelvin_to_rankine => kelvin_to_rankine_slice, kelvin_to_rankine_inplace;
+    rankine_to_kelvin => rankine_to_kelvin_slice, rankine_to_kelvin_inplace;
+    fahrenheit_to_rankine => fahrenheit_to_rankine_slice, fahrenheit_to_rankine_inplace;
+    rankine_to_fahrenheit => rankine_to_fahrenheit_slice, rankine_to_fahrenheit_inplace;
+}
@@ -0,0 +1,83 @@
+use crate::{
+    calculate_apparent_temperature, calculate_heat_index_simplified, calculate_humidex,
+    calculate_normal_effective_temperature, calculate_relative_humidity_percent, calculate_utci,
+    calculate_wbgt, calculate_wbt, wind_chill_if_valid,
+};
+
+/// A single set of meteorological inputs, as taken by [`crate::calculate_all`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MeteoInputs {
+    /// 2m temperature in Kelvin.
+    pub t2_k: f64,
+    /// 2m dew point temperature in Kelvin.
+    pub td_k: f64,
+    /// Wind speed at 10 meters in m/s.
+    pub va: f64,
+    /// Mean radiant temperature in Kelvin.
+    pub mrt: f64,
+}
+
+impl MeteoInputs {
+    /// The relative humidity percentage, as [`calculate_relative_humidity_percent`].
+    pub fn relative_humidity(&self) -> f64 {
+        calculate_relative_humidity_percent(self.t2_k, self.td_k)
+    }
+}
+
+/// A thermal index that can be computed from [`MeteoInputs`].
+///
+/// Each index is a zero-sized type, so generic tooling can hold the whole family as
+/// `Vec<Box<dyn Index>>`, as returned by [`all_indices`].
+pub trait Index {
+    /// The name of the index, matching its [`crate::AllIndices`] field.
+    fn name(&self) -> &'static str;
+
+    /// Calculates the index in Kelvin, or `None` if it does not apply to `inputs`.
+    fn compute(&self, inputs: &MeteoInputs) -> Option<f64>;
+}
+
+macro_rules! indices {
+    ($($(#[$doc:meta])* $index:ident = $name:literal, |$inputs:ident| $compute:expr;)*) => {$(
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $index;
+
+        impl Index for $index {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn compute(&self, $inputs: &MeteoInputs) -> Option<f64> {
+                $compute
+            }
+        }
+    )*
+
+        /// Returns every [`Index`], in [`crate::AllIndices`] field order.
+        pub fn all_indices() -> Vec<Box<dyn Index>> {
+            vec![$(Box::new($index)),*]
+        }
+    };
+}
+
+indices! {
+    /// Universal Thermal Climate Index, see [`calculate_utci`].
+    Utci = "utci", |i| Some(calculate_utci(i.t2_k, i.va, i.mrt, Some(i.td_k), None));
+    /// Wet Bulb Globe Temperature, see [`calculate_wbgt`].
+    Wbgt = "wbgt", |i| Some(calculate_wbgt(i.t2_k, i.mrt, i.va, i.td_k));
+    /// Humidex, see [`calculate_humidex`].
+    Humidex = "humidex", |i| Some(calculate_humidex(i.t2_k, i.td_k));
+    /// Apparent temperature, see [`calculate_apparent_temperature`].
+    ApparentTemperature = "apparent_temperature",
+        |i| Some(calculate_apparent_temperature(i.t2_k, i.va, i.relative_humidity()));
+    /// Simplified heat index, see [`calculate_heat_index_simplified`]. `None` at or below 20°C.
+    HeatIndex = "heat_index", |i| calculate_heat_index_simplified(i.t2_k, i.relative_humidity());
+    /// Wind chill, see [`crate::calculate_wind_chill`]. `None` outside -50°C to 5°C and 5km/h to
+    /// 80km/h.
+    WindChill = "wind_chill", |i| wind_chill_if_valid(i.t2_k, i.va);
+    /// Wet bulb temperature, see [`calculate_wbt`].
+    WetBulbTemperature = "wbt", |i| Some(calculate_wbt(i.t2_k, i.relative_humidity()));
+    /// Normal effective temperature, see [`calculate_normal_effective_temperature`].
+    NormalEffectiveTemperature = "net",
+        |i| Some(calculate_normal_effective_temperature(i.t2_k, i.va, i.relative_humidity()));
+}
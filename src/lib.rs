@@ -1,8 +1,58 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
 use std::f64::consts::PI;
 
+use constants::*;
+
+#[macro_use]
+mod trace;
+
+#[cfg(feature = "ndarray")]
+mod array;
+mod batch;
+mod categories;
+pub mod celsius;
+pub mod constants;
+mod davies_jones;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod helpers;
-
+mod index;
+mod liljegren;
+mod numeric;
+mod pipeline;
+mod series;
+#[cfg(feature = "simd")]
+mod simd;
+mod solar;
+mod two_node;
+mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "ndarray")]
+pub use array::*;
+pub use batch::*;
+pub use categories::*;
+pub use davies_jones::*;
+pub use error::*;
 pub use helpers::*;
+pub use index::*;
+pub use liljegren::*;
+pub use pipeline::*;
+pub use series::*;
+#[cfg(feature = "simd")]
+pub use simd::*;
+pub use solar::*;
+pub use two_node::*;
+pub use units::*;
+
+/// Tetens vapour pressure in hPa at `t_c` in Celsius, as used by
+/// [`calculate_relative_humidity_percent`].
+fn tetens_vapour_pressure(t_c: f64) -> f64 {
+    6.11 * 10.0_f64.powf(7.5 * t_c / (237.3 + t_c))
+}
 
 /// Calculates relative humidity from temperatures and dew point temperature.
 ///
@@ -12,18 +62,54 @@ pub use helpers::*;
 ///
 /// The return value is relative humidity as a percentage.
 ///
+/// This uses different Magnus coefficients from [`calculate_dew_point_from_relative_humidity`], so
+/// converting relative humidity to dew point and back drifts by up to 0.6 percentage points
+/// between -40°C and 50°C. Both formulas are kept to match thermofeel.
+///
 /// Reference: <https://www.theweatherprediction.com/habyhints/186/>
 pub fn calculate_relative_humidity_percent(t2_k: f64, td_k: f64) -> f64 {
+    trace_span!("calculate_relative_humidity_percent", t2_k, td_k);
     let t2_c = kelvin_to_celsius(t2_k);
     let td_c = kelvin_to_celsius(td_k);
 
-    let es = 6.11 * f64::from(10.0).powf(7.5 * t2_c / (237.3 + t2_c));
+    let es = tetens_vapour_pressure(t2_c);
 
-    let e = (6.11) * f64::from(10.0).powf(7.5 * td_c / (237.3 + td_c));
-    println!("{t2_c} {td_c}");
+    let e = tetens_vapour_pressure(td_c);
+    trace_values!(es, e);
     (e / es) * 100.0
 }
 
+/// Clamps a relative humidity to the physical range of 0% to 100%.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is `rh` limited to 0 to 100, with NaN passed through. Air cannot hold more
+/// vapour than saturation outside of transient supersaturation in clouds, so values above 100% from
+/// surface data come from instrument error or mismatched formulas, such as
+/// [`calculate_relative_humidity_percent`] with a dew point a fraction above the air temperature.
+/// Values below 0% have no physical meaning.
+///
+/// The indices that take relative humidity directly, such as [`calculate_wbt`],
+/// [`calculate_wbgt_simple`], [`calculate_apparent_temperature`] and
+/// [`calculate_heat_index_simplified`], apply this to their input, so 120% is treated as 100%.
+/// Conversions between humidity measures, such as [`relative_humidity_to_vapour_pressure`], do not,
+/// so that they stay exact inverses.
+pub fn normalize_relative_humidity(rh: f64) -> f64 {
+    rh.clamp(0.0, 100.0)
+}
+
+/// Calculates relative humidity from temperatures and dew point temperature, clamped to 0% to 100%.
+///
+/// Where `t2_k` is the temperature at 2 meters in Kelvin.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// The return value is [`calculate_relative_humidity_percent`] passed through
+/// [`normalize_relative_humidity`], so it is safe to feed to indices that expect at most 100%.
+pub fn calculate_relative_humidity_percent_clamped(t2_k: f64, td_k: f64) -> f64 {
+    normalize_relative_humidity(calculate_relative_humidity_percent(t2_k, td_k))
+}
+
 /// Calculates saturation vapour pressure over water.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
@@ -32,25 +118,91 @@ pub fn calculate_relative_humidity_percent(t2_k: f64, td_k: f64) -> f64 {
 ///
 /// Reference: Hardy (1998) [https://www.decatur.de/javascript/dew/resources/its90formulas.pdf](https://www.decatur.de/javascript/dew/resources/its90formulas.pdf)
 pub fn calculate_saturation_vapour_pressure(t2_k: f64) -> f64 {
-    let g = [
-        -2.8365744e3,
-        -6.028076559e3,
-        1.954263612e1,
-        -2.737830188e-2,
-        1.6261698e-5,
-        7.0229056e-10,
-        -1.8680009e-13,
-        2.7150305,
-    ];
+    let g = HARDY_WATER_COEFFICIENTS;
+
+    let ln_es = g[0] * t2_k.powi(-2)
+        + g[1] * t2_k.powi(-1)
+        + g[2]
+        + g[3] * t2_k
+        + g[4] * t2_k.powi(2)
+        + g[5] * t2_k.powi(3)
+        + g[6] * t2_k.powi(4)
+        + g[7] * t2_k.ln();
+
+    ln_es.exp() * 0.01 // hPa
+}
 
-    let mut ess = g[7] * t2_k.ln();
-    for i in 0..g.len() - 1 {
-        ess = ess + g[i] * t2_k.powi(i as i32 - 2);
-    }
+// Hardy (1998): ln(es) = g0 T^-2 + g1 T^-1 + g2 + g3 T + g4 T^2 + g5 T^3 + g6 T^4 + g7 ln(T), es in Pa
+const HARDY_WATER_COEFFICIENTS: [f64; 8] = [
+    -2.8365744e3,
+    -6.028076559e3,
+    1.954263612e1,
+    -2.737830188e-2,
+    1.6261698e-5,
+    7.0229056e-10,
+    -1.8680009e-13,
+    2.7150305,
+];
+
+/// Calculates the temperature derivative of saturation vapour pressure over water.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the derivative of [`calculate_saturation_vapour_pressure`] with respect to
+/// temperature in hPa K-1. Since that function is `es = exp(ln es)`, its derivative is
+/// `es d(ln es)/dT`, where differentiating the Hardy polynomial term by term gives
+/// `d(ln es)/dT = -2 g0 T^-3 - g1 T^-2 + g3 + 2 g4 T + 3 g5 T^2 + 4 g6 T^3 + g7 / T`.
+/// This is the slope needed by Newton iterations on humidity, and is about 1.10 hPa K-1 at 15°C.
+///
+/// Reference: Hardy (1998) [https://www.decatur.de/javascript/dew/resources/its90formulas.pdf](https://www.decatur.de/javascript/dew/resources/its90formulas.pdf)
+pub fn calculate_saturation_vapour_pressure_derivative(t2_k: f64) -> f64 {
+    let g = HARDY_WATER_COEFFICIENTS;
+
+    let dln_es = -2.0 * g[0] * t2_k.powi(-3) - g[1] * t2_k.powi(-2)
+        + g[3]
+        + 2.0 * g[4] * t2_k
+        + 3.0 * g[5] * t2_k.powi(2)
+        + 4.0 * g[6] * t2_k.powi(3)
+        + g[7] / t2_k;
+
+    calculate_saturation_vapour_pressure(t2_k) * dln_es
+}
 
-    ess = ess.exp() * 0.01; // hPa
+/// Calculates the enhancement factor of water vapour in moist air.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// Where `t2_c` is the air temperature in Celsius.
+///
+/// The return value is the dimensionless ratio of the saturation vapour pressure in moist air to
+/// that of pure water vapour, `f(p, T) = 1 + 10^-4 (7.2 + p (0.0320 + 5.9e-6 T^2))`. This is Buck's
+/// later temperature-dependent fit over water, which refines the `1.0007 + 3.46e-6 p` of Buck
+/// (1981); the two agree to within 0.1% from 500 hPa to 1100 hPa and -40°C to 40°C.
+///
+/// Reference: Buck (1996), Buck Research CR-1A User's Manual, Appendix 1
+///
+/// See also: Buck (1981) [https://doi.org/10.1175/1520-0450(1981)020<1527:NEFCVP>2.0.CO;2](https://doi.org/10.1175/1520-0450(1981)020<1527:NEFCVP>2.0.CO;2)
+fn enhancement_factor(pressure_hpa: f64, t2_c: f64) -> f64 {
+    1.0 + 1e-4 * (7.2 + pressure_hpa * (0.0320 + 5.9e-6 * t2_c * t2_c))
+}
 
-    ess
+/// Calculates saturation vapour pressure over water in moist air at a given pressure.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is saturation vapour pressure over water in hPa (mBar),
+/// [`calculate_saturation_vapour_pressure`] multiplied by the Buck enhancement factor
+/// `f(p, T) = 1 + 10^-4 (7.2 + p (0.0320 + 5.9e-6 T^2))`, with `p` in hPa and `T` in Celsius. The
+/// factor accounts for air raising the vapour pressure water can hold against it, compared to pure
+/// vapour. It adds about 0.4% at sea level and 20°C, about 0.25% at 500 hPa, and grows with
+/// temperature and pressure.
+///
+/// Reference: Buck (1996), Buck Research CR-1A User's Manual, Appendix 1
+pub fn calculate_saturation_vapour_pressure_enhanced(t2_k: f64, pressure_hpa: f64) -> f64 {
+    let f = enhancement_factor(pressure_hpa, kelvin_to_celsius(t2_k));
+    calculate_saturation_vapour_pressure(t2_k) * f
 }
 
 /// Represents the phase of water for saturation vapor pressure calculations.
@@ -75,7 +227,7 @@ pub enum Phase {
 ///
 /// See also: [https://metview.readthedocs.io/en/latest/api/functions/saturation_vapour_pressure.html](https://metview.readthedocs.io/en/latest/api/functions/saturation_vapour_pressure.html)
 pub fn calculate_saturation_vapour_pressure_multiphase(t2_k: f64, phase: Phase) -> f64 {
-    let t0 = 273.16; // triple point of water 273.16 K (0.01 °C) at 611.73 Pa
+    let t0 = TRIPLE_POINT_K;
 
     match phase {
         Phase::Liquid => {
@@ -89,6 +241,68 @@ pub fn calculate_saturation_vapour_pressure_multiphase(t2_k: f64, phase: Phase)
     }
 }
 
+/// Calculates saturation vapour pressure over liquid water or ice with the Arden Buck equations.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `phase` specifies whether to calculate over liquid water or ice.
+///
+/// The return value is saturation vapour pressure in the pure phase in hPa (mBar),
+/// `a exp((b - T/d) T / (c + T))` with `T` in Celsius. The coefficients are, over water,
+/// `a = 6.1121`, `b = 18.678`, `c = 257.14` and `d = 234.5`, and over ice, `a = 6.1115`,
+/// `b = 23.036`, `c = 279.82` and `d = 333.7`. These extend the Magnus form of
+/// [`calculate_saturation_vapour_pressure_multiphase`] with the `T/d` term while staying as cheap.
+/// Compared with [`calculate_saturation_vapour_pressure`] and
+/// [`calculate_saturation_vapour_pressure_ice_hardy`], they agree within about 0.05% from -20°C to
+/// 50°C over water and from -20°C to 0°C over ice, drifting to 0.3% at -40°C over supercooled water
+/// and at -80°C over ice.
+///
+/// Reference: Buck (1996), Buck Research CR-1A User's Manual, Appendix 1, which updates the
+/// coefficients of Buck (1981)
+///
+/// See also: Buck (1981) [https://doi.org/10.1175/1520-0450(1981)020<1527:NEFCVP>2.0.CO;2](https://doi.org/10.1175/1520-0450(1981)020<1527:NEFCVP>2.0.CO;2)
+pub fn calculate_saturation_vapour_pressure_buck(t2_k: f64, phase: Phase) -> f64 {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let (a, b, c, d) = match phase {
+        Phase::Liquid => (6.1121, 18.678, 257.14, 234.5),
+        Phase::Ice => (6.1115, 23.036, 279.82, 333.7),
+    };
+    a * ((b - t2_c / d) * t2_c / (c + t2_c)).exp()
+}
+
+/// Calculates saturation vapour pressure over ice.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is saturation vapor pressure over ice in the pure phase in hPa (mBar).
+///
+/// This is the ITS-90 ice formulation from the same paper as [`calculate_saturation_vapour_pressure`],
+/// so the two meet at the triple point (611.657 Pa). The [`Phase::Ice`] branch of
+/// [`calculate_saturation_vapour_pressure_multiphase`] is a simpler Magnus form scaled to 611.21 Pa at
+/// the triple point, which is about 0.07% lower there and within about 0.3% down to -60°C.
+///
+/// Reference: Hardy (1998) [https://www.decatur.de/javascript/dew/resources/its90formulas.pdf](https://www.decatur.de/javascript/dew/resources/its90formulas.pdf)
+pub fn calculate_saturation_vapour_pressure_ice_hardy(t2_k: f64) -> f64 {
+    // Hardy (1998): ln(ei) = k0 T^-1 + k1 + k2 T + k3 T^2 + k4 T^3 + k5 ln(T), ei in Pa
+    let k = [
+        -5.8666426e3,
+        2.232870244e1,
+        1.39387003e-2,
+        -3.4262402e-5,
+        2.7040955e-8,
+        6.7063522e-1,
+    ];
+
+    let ln_ei = k[0] * t2_k.powi(-1)
+        + k[1]
+        + k[2] * t2_k
+        + k[3] * t2_k.powi(2)
+        + k[4] * t2_k.powi(3)
+        + k[5] * t2_k.ln();
+
+    ln_ei.exp() * 0.01 // hPa
+}
+
 /// Calculates non-saturated vapour pressure.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
@@ -103,6 +317,125 @@ pub fn calculate_nonsaturation_vapour_pressure(t2_k: f64, rh: f64) -> f64 {
     rh / 100.0 * 6.105 * (17.27 * t2_c / (237.7 + t2_c)).exp()
 }
 
+/// Converts relative humidity to water vapour pressure.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is the water vapour pressure in hPa (mBar), `rh` percent of
+/// [`calculate_saturation_vapour_pressure`]. This is the inverse of
+/// [`vapour_pressure_to_relative_humidity`].
+pub fn relative_humidity_to_vapour_pressure(t2_k: f64, rh: f64) -> f64 {
+    calculate_saturation_vapour_pressure(t2_k) * rh / 100.0
+}
+
+/// Converts water vapour pressure to relative humidity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `eh_pa` is the water vapour pressure in hPa (mBar).
+///
+/// The return value is the relative humidity percentage, `eh_pa` as a percentage of
+/// [`calculate_saturation_vapour_pressure`]. This is the inverse of
+/// [`relative_humidity_to_vapour_pressure`].
+pub fn vapour_pressure_to_relative_humidity(t2_k: f64, eh_pa: f64) -> f64 {
+    eh_pa / calculate_saturation_vapour_pressure(t2_k) * 100.0
+}
+
+/// Converts specific humidity to relative humidity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `q` is the 2m specific humidity in kg kg-1.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the relative humidity percentage: the water vapour pressure from
+/// [`vapour_pressure_from_specific_humidity`] as a percentage of
+/// [`calculate_saturation_vapour_pressure`], as [`vapour_pressure_to_relative_humidity`]. Values
+/// above 100% are returned as-is, since supersaturated model output is better flagged than hidden.
+pub fn calculate_relative_humidity_from_specific_humidity(
+    t2_k: f64,
+    q: f64,
+    pressure_hpa: f64,
+) -> f64 {
+    let e = vapour_pressure_from_specific_humidity(q, pressure_hpa);
+    vapour_pressure_to_relative_humidity(t2_k, e)
+}
+
+/// Calculates vapour pressure deficit (VPD).
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is the vapour pressure deficit in hPa (mBar): the saturation vapour pressure
+/// from [`calculate_saturation_vapour_pressure`] less the actual vapour pressure at `rh`. It is zero
+/// for saturated air. Divide by 10 for kPa, the unit usually quoted in plant science.
+pub fn calculate_vapour_pressure_deficit(t2_k: f64, rh: f64) -> f64 {
+    let es = calculate_saturation_vapour_pressure(t2_k);
+    es - es * rh / 100.0
+}
+
+/// Calculates the latent heat of vaporization of water.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the latent heat of vaporization in J kg-1, `L = 2.501e6 - 2361 Tc` with `Tc`
+/// in Celsius. This linear approximation is valid for liquid water from about -20°C to 40°C.
+///
+/// Reference: Allen et al. (1998), FAO Irrigation and Drainage Paper 56, Annex 3
+pub fn calculate_latent_heat_vaporization(t2_k: f64) -> f64 {
+    let t2_c = kelvin_to_celsius(t2_k);
+    2.501e6 - 2361.0 * t2_c
+}
+
+/// Calculates the mixing ratio of water vapour to dry air.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the mixing ratio in kg kg-1, `w = 0.622 e / (p - e)`, with the vapour
+/// pressure `e` from [`relative_humidity_to_vapour_pressure`].
+pub fn calculate_mixing_ratio(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let e = relative_humidity_to_vapour_pressure(t2_k, rh);
+    mixing_ratio_from_vapour_pressure(e, pressure_hpa)
+}
+
+/// The mixing ratio in kg kg-1 of [`calculate_mixing_ratio`], for a vapour pressure `eh_pa` in hPa
+/// from any saturation formula.
+pub(crate) fn mixing_ratio_from_vapour_pressure(eh_pa: f64, pressure_hpa: f64) -> f64 {
+    MOLAR_MASS_RATIO * eh_pa / (pressure_hpa - eh_pa)
+}
+
+/// Calculates the equivalent temperature.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the equivalent temperature in Kelvin, `Te = T + L w / cp`: the temperature the
+/// air would reach if all its water vapour condensed and the latent heat warmed it. `L` is from
+/// [`calculate_latent_heat_vaporization`], `w` from [`calculate_mixing_ratio`] and `cp` is
+/// [`SPECIFIC_HEAT_DRY_AIR`].
+///
+/// Reference: American Meteorological Society, Glossary of Meteorology, "Equivalent temperature"
+pub fn calculate_equivalent_temperature(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let w = calculate_mixing_ratio(t2_k, rh, pressure_hpa);
+    t2_k + calculate_latent_heat_vaporization(t2_k) * w / SPECIFIC_HEAT_DRY_AIR
+}
+
+/// Treats negative wind speeds as calm, keeping NaN so that missing data stays missing.
+fn calm_if_negative(va: f64) -> f64 {
+    if va < 0.0 { 0.0 } else { va }
+}
+
 /// Scales wind speed from 10 meters to a specified height.
 ///
 /// Where `va` is the 10m wind speed in m/s.
@@ -111,13 +444,37 @@ pub fn calculate_nonsaturation_vapour_pressure(t2_k: f64, rh: f64) -> f64 {
 ///
 /// The return value is the wind speed at height `h`.
 ///
+/// Negative wind speeds are treated as calm (0 m/s), so every index built on this function
+/// receives a non-negative wind speed.
+///
+/// The logarithmic wind profile assumes a roughness length of 0.01 m, so `h` must be above 0.01 m;
+/// heights below 10 m scale the wind down and heights above scale it up. At 0.01 m the result is
+/// zero, and below it is negative or NaN. Use [`scale_windspeed_checked`] when `h` is not known to
+/// be valid.
+///
 /// Reference: Bröde et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
 pub fn scale_windspeed(va: f64, h: f64) -> f64 {
+    let va = calm_if_negative(va);
     let target_height = 10.0;
-    let c = 1.0 / f64::from(target_height / 0.01).log10();
-    let vh = va * (h / 0.01).log10() * c;
+    let c = 1.0 / (target_height / ROUGHNESS_LENGTH_M).log10();
+    va * (h / ROUGHNESS_LENGTH_M).log10() * c
+}
 
-    return vh;
+/// Scales wind speed from 10 meters to a specified height, rejecting nonphysical heights.
+///
+/// Where `va` is the 10m wind speed in m/s.
+///
+/// Where `h` is the target height in meters at which wind speed needs to be scaled.
+///
+/// The return value is the wind speed at height `h`, as [`scale_windspeed`], or `None` unless `h`
+/// is above the 0.01 m roughness length of the logarithmic profile. Heights at or below it, and
+/// NaN, would otherwise give a zero, negative or NaN wind speed.
+pub fn scale_windspeed_checked(va: f64, h: f64) -> Option<f64> {
+    if h > ROUGHNESS_LENGTH_M {
+        Some(scale_windspeed(va, h))
+    } else {
+        None
+    }
 }
 
 /// Approximates direct solar radiation from total sky direct solar radiation and cosine of solar zenith angle.
@@ -138,6 +495,42 @@ pub fn approximate_dsrp(fdir: f64, cossza: f64) -> Option<f64> {
     }
 }
 
+/// Splits global horizontal irradiance into its direct and diffuse parts with the Erbs model.
+///
+/// Where `ssrd` is the surface solar radiation downwards (global horizontal irradiance) in W m-2.
+///
+/// Where `cossza` is the cosine of the solar zenith angle (dimensionless).
+///
+/// The return value is `(fdir, diffuse)`: the direct solar radiation on a horizontal surface, as
+/// taken by [`calculate_mean_radiant_temperature`], and the diffuse solar radiation, both in W m-2.
+/// They always sum to `ssrd`.
+///
+/// The clearness index `kt = ssrd / (I0 cossza)` compares the irradiance with that at the top of
+/// the atmosphere, with `I0` the [`constants::SOLAR_CONSTANT`]. The diffuse fraction is
+/// `1 - 0.09 kt` for overcast skies up to `kt = 0.22`, a quartic in `kt` up to 0.8, and 0.165 for
+/// clear skies above. With the sun at or below the horizon all radiation is taken as diffuse.
+/// Combine with [`approximate_dsrp`] to estimate `dsrp`.
+///
+/// Reference: Erbs et al. (1982), Estimation of the diffuse radiation fraction for hourly, daily and
+/// monthly-average global radiation, Solar Energy 28(4)
+pub fn split_global_irradiance(ssrd: f64, cossza: f64) -> (f64, f64) {
+    if cossza <= 0.0 {
+        return (0.0, ssrd);
+    }
+
+    let kt = ssrd / (SOLAR_CONSTANT * cossza);
+    let kd = if kt <= 0.22 {
+        1.0 - 0.09 * kt
+    } else if kt <= 0.8 {
+        0.9511 - 0.1604 * kt + 4.388 * kt.powi(2) - 16.638 * kt.powi(3) + 12.336 * kt.powi(4)
+    } else {
+        0.165
+    };
+
+    let diffuse = kd * ssrd;
+    (ssrd - diffuse, diffuse)
+}
+
 /// Calculates dew point temperature at 2m from relative humidity.
 ///
 /// Where `rh` is the relative humidity in percent.
@@ -146,6 +539,8 @@ pub fn approximate_dsrp(fdir: f64, cossza: f64) -> Option<f64> {
 ///
 /// The return value is the dew point temperature in Kelvin.
 ///
+/// This is only an approximate inverse of [`calculate_relative_humidity_percent`], see its documentation.
+///
 /// Reference: Alduchov and Eskridge (1996) [https://doi.org/10.1175/1520-0450(1996)035<0601:IMFAOS>2.0.CO;2](https://doi.org/10.1175/1520-0450(1996)035<0601:IMFAOS>2.0.CO;2)
 pub fn calculate_dew_point_from_relative_humidity(rh: f64, t2_k: f64) -> f64 {
     let t2_c = kelvin_to_celsius(t2_k);
@@ -154,6 +549,228 @@ pub fn calculate_dew_point_from_relative_humidity(rh: f64, t2_k: f64) -> f64 {
     celsius_to_kelvin(td_c)
 }
 
+/// Calculates the frost point temperature at 2m from relative humidity.
+///
+/// Where `rh` is the relative humidity over water in percent.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the frost point temperature in Kelvin: the temperature at which the air would
+/// be saturated with respect to ice. The vapour pressure is found over water, as relative humidity
+/// is conventionally reported, then inverted with the Alduchov and Eskridge (1996) Magnus form over
+/// ice. The frost point is above the dew point below freezing, since ice holds less vapour.
+///
+/// Reference: Alduchov and Eskridge (1996) [https://doi.org/10.1175/1520-0450(1996)035<0601:IMFAOS>2.0.CO;2](https://doi.org/10.1175/1520-0450(1996)035<0601:IMFAOS>2.0.CO;2)
+pub fn calculate_frost_point_from_relative_humidity(rh: f64, t2_k: f64) -> f64 {
+    let e = magnus_vapour_pressure(kelvin_to_celsius(t2_k)) * rh / 100.0;
+    let ln_e = (e / 6.1121).ln();
+    celsius_to_kelvin(273.86 * ln_e / (22.587 - ln_e))
+}
+
+/// Calculates the dew point above freezing and the frost point below it.
+///
+/// Where `rh` is the relative humidity over water in percent.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the temperature in Kelvin at which the air would become saturated and form
+/// dew or frost: [`calculate_dew_point_from_relative_humidity`] when that is at or above 0°C, and
+/// [`calculate_frost_point_from_relative_humidity`] otherwise, as meteorological services report it.
+///
+/// The choice is made on the dew point, not the air temperature, so warm dry air with a dew point
+/// below freezing gets a frost point. This keeps the result continuous: the two Magnus forms give
+/// 6.1094 hPa and 6.1121 hPa at 0°C, so the frost point just after the switch is only 0.005 K below
+/// freezing.
+pub fn calculate_dew_point_auto(rh: f64, t2_k: f64) -> f64 {
+    let td_k = calculate_dew_point_from_relative_humidity(rh, t2_k);
+    if td_k >= ZERO_CELSIUS_K {
+        td_k
+    } else {
+        calculate_frost_point_from_relative_humidity(rh, t2_k)
+    }
+}
+
+/// Calculates the wet bulb depression.
+///
+/// Where `t2_k` is the 2m (dry bulb) temperature in Kelvin.
+///
+/// Where `twb_k` is the wet bulb temperature in Kelvin.
+///
+/// The return value is the wet bulb depression `t2_k - twb_k` in K. It is zero in saturated air and
+/// grows as the air dries, which is what the psychrometer equation turns into a vapour pressure.
+pub fn calculate_wet_bulb_depression(t2_k: f64, twb_k: f64) -> f64 {
+    t2_k - twb_k
+}
+
+/// Calculates the dew point depression.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is the dew point depression, or spread, `t2_k - td_k` in K. It is zero in
+/// saturated air and grows as the air dries.
+pub fn calculate_dew_point_depression(t2_k: f64, td_k: f64) -> f64 {
+    t2_k - td_k
+}
+
+/// Estimates the height of the base of convective cloud.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is the cloud base height above the ground in meters, 125 m per K of
+/// [`calculate_dew_point_depression`]. A rising parcel cools at the dry adiabatic lapse rate of
+/// about 9.8 K km-1 while its dew point falls by about 1.8 K km-1, so the two meet, and cloud
+/// forms, after about 1 km for every 8 K of spread. This assumes a well-mixed boundary layer, as on
+/// a sunny afternoon, and estimates the lifting condensation level of surface air; it says nothing
+/// about stratiform or frontal cloud. A dew point above the air temperature, which only bad data
+/// gives, is treated as saturated air with cloud at the ground.
+///
+/// Reference: Lawrence (2005) [https://doi.org/10.1175/BAMS-86-2-225](https://doi.org/10.1175/BAMS-86-2-225)
+pub fn calculate_cloud_base_height(t2_k: f64, td_k: f64) -> f64 {
+    125.0 * calculate_dew_point_depression(t2_k, td_k).max(0.0)
+}
+
+/// Calculates the psychrometric constant.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the psychrometric constant `cp p / (0.622 L)` in hPa K-1, with `cp` of
+/// [`SPECIFIC_HEAT_DRY_AIR`] and latent heat `L = 2.45e6 J kg-1`, its value at 20°C. This is about
+/// 0.67 hPa K-1 at 1013.25 hPa, in line with the 0.665 hPa K-1 usually quoted. It is the
+/// thermodynamic value for an ideal wet bulb; the empirical A p of
+/// [`constants::PSYCHROMETER_COEFFICIENT`] used with real psychrometers is about 1% lower.
+///
+/// Reference: Allen et al. (1998), FAO Irrigation and Drainage Paper 56, equation 8
+pub fn calculate_psychrometric_constant(pressure_hpa: f64) -> f64 {
+    SPECIFIC_HEAT_DRY_AIR * pressure_hpa / (MOLAR_MASS_RATIO * 2.45e6)
+}
+
+/// Alduchov and Eskridge (1996) Magnus saturation vapour pressure over water in hPa.
+fn magnus_vapour_pressure(t_c: f64) -> f64 {
+    6.1094 * ((17.625 * t_c) / (243.04 + t_c)).exp()
+}
+
+/// Calculates dew point temperature from water vapour pressure.
+///
+/// Where `eh_pa` is the water vapour pressure in hPa (mBar).
+///
+/// The return value is the dew point temperature in Kelvin, the temperature at which
+/// [`calculate_saturation_vapour_pressure`] equals `eh_pa`. The Hardy (1998) formula has no closed
+/// form inverse, so it is solved by Newton's method with
+/// [`calculate_saturation_vapour_pressure_derivative`], bracketed by the -100°C to 100°C range the
+/// formula is valid over. The result is NaN outside that range, including for a zero or negative
+/// vapour pressure.
+///
+/// This is the saturation formula shared by the conversions between humidity measures, such as
+/// [`relative_humidity_to_vapour_pressure`], [`calculate_relative_humidity_from_specific_humidity`],
+/// [`calculate_dew_point_from_specific_humidity`] and the wet bulb conversions, so their round trips
+/// are exact. [`calculate_relative_humidity_percent`] and
+/// [`calculate_dew_point_from_relative_humidity`] keep the Tetens and Magnus forms of thermofeel
+/// instead, see their documentation.
+///
+/// Reference: Hardy (1998) [https://www.decatur.de/javascript/dew/resources/its90formulas.pdf](https://www.decatur.de/javascript/dew/resources/its90formulas.pdf)
+pub fn calculate_dew_point_from_vapour_pressure(eh_pa: f64) -> f64 {
+    let options = numeric::SolverOptions {
+        tolerance: 1e-10,
+        max_iterations: 100,
+    };
+    numeric::newton_bisect(
+        |t_k| calculate_saturation_vapour_pressure(t_k) - eh_pa,
+        calculate_saturation_vapour_pressure_derivative,
+        celsius_to_kelvin(-100.0),
+        celsius_to_kelvin(100.0),
+        options,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+/// Vapour pressure in hPa from the psychrometer equation `e = es(twb) - A p (t2 - twb)`.
+fn psychrometer_vapour_pressure(t2_k: f64, twb_k: f64, pressure_hpa: f64) -> f64 {
+    let es_wb = calculate_saturation_vapour_pressure(twb_k);
+    es_wb - PSYCHROMETER_COEFFICIENT * pressure_hpa * (t2_k - twb_k)
+}
+
+/// Calculates dew point temperature from wet bulb temperature.
+///
+/// Where `t2_k` is the 2m (dry bulb) temperature in Kelvin.
+///
+/// Where `twb_k` is the wet bulb temperature in Kelvin.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the dew point temperature in Kelvin. The vapour pressure is found from the
+/// psychrometer equation `e = es(twb) - A p (t2 - twb)`, with the aspirated psychrometer coefficient
+/// `A` of [`constants::PSYCHROMETER_COEFFICIENT`], and then inverted to a dew point. The same wet bulb
+/// depression means less vapour at higher pressure, so the dew point falls as pressure rises.
+///
+/// Saturation vapour pressure and its inverse use the Hardy (1998) formula, through
+/// [`calculate_dew_point_from_vapour_pressure`].
+///
+/// Reference: WMO (2018), Guide to Instruments and Methods of Observation, Volume I, Annex 4.B
+pub fn calculate_dew_point_from_wet_bulb(t2_k: f64, twb_k: f64, pressure_hpa: f64) -> f64 {
+    let e = psychrometer_vapour_pressure(t2_k, twb_k, pressure_hpa);
+    calculate_dew_point_from_vapour_pressure(e)
+}
+
+/// Calculates dew point temperature from specific humidity.
+///
+/// Where `q` is the specific humidity in kg kg-1.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the dew point temperature in Kelvin. The water vapour pressure from
+/// [`vapour_pressure_from_specific_humidity`] is inverted to a dew point by
+/// [`calculate_dew_point_from_vapour_pressure`]. No air temperature is needed. Vapour pressure is proportional to pressure, so the same specific humidity has a much
+/// lower dew point at altitude than at sea level: 5 g kg-1 is a dew point of about 4°C at 1000 hPa
+/// but about -6°C at 500 hPa. The saturation formula is over water, so very dry air aloft gets a
+/// dew point rather than a frost point. The result is NaN if `q` is zero or negative.
+///
+/// Reference: Hardy (1998) [https://www.decatur.de/javascript/dew/resources/its90formulas.pdf](https://www.decatur.de/javascript/dew/resources/its90formulas.pdf)
+pub fn calculate_dew_point_from_specific_humidity(q: f64, pressure_hpa: f64) -> f64 {
+    let e = vapour_pressure_from_specific_humidity(q, pressure_hpa);
+    calculate_dew_point_from_vapour_pressure(e)
+}
+
+/// Calculates relative humidity from dry bulb and wet bulb temperatures.
+///
+/// Where `t2_k` is the 2m (dry bulb) temperature in Kelvin.
+///
+/// Where `twb_k` is the wet bulb temperature in Kelvin.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the relative humidity percentage. The vapour pressure is found from the
+/// psychrometer equation, as in [`calculate_dew_point_from_wet_bulb`], with the aspirated
+/// psychrometer coefficient `A = 6.53e-4 K-1` of [`constants::PSYCHROMETER_COEFFICIENT`], and
+/// divided by the saturation vapour pressure at the dry bulb temperature, as
+/// [`vapour_pressure_to_relative_humidity`]. Both saturation vapour pressures use the Hardy (1998)
+/// formula of [`calculate_saturation_vapour_pressure`]. A sling psychrometer is ventilated enough
+/// that the same coefficient applies.
+///
+/// Reference: WMO (2018), Guide to Instruments and Methods of Observation, Volume I, Annex 4.B
+pub fn calculate_relative_humidity_from_wet_bulb(t2_k: f64, twb_k: f64, pressure_hpa: f64) -> f64 {
+    let e = psychrometer_vapour_pressure(t2_k, twb_k, pressure_hpa);
+    vapour_pressure_to_relative_humidity(t2_k, e)
+}
+
+/// Calculates the projected area factor of a standing person.
+///
+/// Where `cossza` is the cosine of the solar zenith angle (dimensionless).
+///
+/// The return value is the fraction of the body surface projected onto a plane normal to the direct
+/// solar beam (dimensionless). The posture is that assumed by Di Napoli et al.: a standing,
+/// rotationally symmetric person, for whom `fp` peaks at about 0.308 with the sun on the horizon and
+/// falls as the sun rises overhead. The angle `gamma = asin(cossza)` is the solar elevation.
+///
+/// Reference: Di Napoli et al. (2020) [https://link.springer.com/article/10.1007/s00484-020-01900-5](https://link.springer.com/article/10.1007/s00484-020-01900-5)
+pub fn calculate_projected_area_factor(cossza: f64) -> f64 {
+    let gamma = cossza.asin() * 180.0 / PI;
+    0.308 * ((PI / 180.0) * gamma * (0.998 - gamma * gamma / 50000.0)).cos()
+}
+
 /// Calculates Mean Radiant Temperature (MRT).
 ///
 /// Where `ssrd` is the surface solar radiation downwards in W m-2.
@@ -181,295 +798,275 @@ pub fn calculate_mean_radiant_temperature(
     fdir: f64,
     strr: f64,
     cossza: f64,
+) -> f64 {
+    calculate_mean_radiant_temperature_longwave(ssrd, ssr, dsrp, strd, fdir, strd - strr, cossza)
+}
+
+/// Calculates Mean Radiant Temperature (MRT) from separate downward and upward longwave fluxes.
+///
+/// Where `ssrd` is the surface solar radiation downwards in W m-2.
+///
+/// Where `ssr` is the surface net solar radiation in W m-2.
+///
+/// Where `dsrp` is the direct solar radiation in W m-2.
+///
+/// Where `strd` is the surface thermal radiation downwards in W m-2.
+///
+/// Where `fdir` is the total sky direct solar radiation at surface in W m-2.
+///
+/// Where `lur` is the surface thermal radiation upwards in W m-2.
+///
+/// Where `cossza` is the cosine of the solar zenith angle (dimensionless).
+///
+/// The return value is the mean radiant temperature in Kelvin. The net thermal radiation used by
+/// [`calculate_mean_radiant_temperature`] is `strr = strd - lur`, so this is the same calculation for
+/// users who measure both longwave fluxes directly.
+///
+/// Reference: Di Napoli et al. (2020) [https://link.springer.com/article/10.1007/s00484-020-01900-5](https://link.springer.com/article/10.1007/s00484-020-01900-5)
+pub fn calculate_mean_radiant_temperature_longwave(
+    ssrd: f64,
+    ssr: f64,
+    dsrp: f64,
+    strd: f64,
+    fdir: f64,
+    lur: f64,
+    cossza: f64,
 ) -> f64 {
     let dsw = ssrd - fdir;
     let rsw = ssrd - ssr;
-    let lur = strd - strr;
 
-    let gamma = cossza.asin() * 180.0 / PI;
-    let fp = 0.308 * ((PI / 180.0) * gamma * (0.998 - gamma * gamma / 50000.0)).cos();
+    let fp = calculate_projected_area_factor(cossza);
+
+    trace_values!(dsw, rsw, fp);
 
-    let mrt = ((1.0 / 0.0000000567)
+    ((1.0 / STEFAN_BOLTZMANN)
         * (0.5 * strd + 0.5 * lur + (0.7 / 0.97) * (0.5 * dsw + 0.5 * rsw + fp * dsrp)))
-        .powf(0.25);
+        .powf(0.25)
+}
 
-    return mrt;
+/// Coefficients of the UTCI polynomial. Each line holds the coefficients of increasing powers of
+/// `t2m` for one power of `va`, `e_mrt` and `wvp`, given in the comment.
+#[rustfmt::skip]
+const UTCI_COEFFICIENTS: [f64; 210] = [
+    6.07562052e-01, -2.27712343e-02, 8.06470249e-04, -1.54271372e-04, -3.24651735e-06, 7.32602852e-08, 1.35959073e-09, // wvp^0 e_mrt^0 va^0
+    -2.25836520e00, 8.80326035e-02, 2.16844454e-03, -1.53347087e-05, -5.72983704e-07, -2.55090145e-09, // wvp^0 e_mrt^0 va^1
+    -7.51269505e-01, -4.08350271e-03, -5.21670675e-05, 1.94544667e-06, 1.14099531e-08, // wvp^0 e_mrt^0 va^2
+    1.58137256e-01, -6.57263143e-05, 2.22697524e-07, -4.16117031e-08, // wvp^0 e_mrt^0 va^3
+    -1.27762753e-02, 9.66891875e-06, 2.52785852e-09, // wvp^0 e_mrt^0 va^4
+    4.56306672e-04, -1.74202546e-07, // wvp^0 e_mrt^0 va^5
+    -5.91491269e-06, // wvp^0 e_mrt^0 va^6
+    3.98374029e-01, 1.83945314e-04, -1.73754510e-04, -7.60781159e-07, 3.77830287e-08, 5.43079673e-10, // wvp^0 e_mrt^1 va^0
+    -2.00518269e-02, 8.92859837e-04, 3.45433048e-06, -3.77925774e-07, -1.69699377e-09, // wvp^0 e_mrt^1 va^1
+    1.69992415e-04, -4.99204314e-05, 2.47417178e-07, 1.07596466e-08, // wvp^0 e_mrt^1 va^2
+    8.49242932e-05, 1.35191328e-06, -6.21531254e-09, // wvp^0 e_mrt^1 va^3
+    -4.99410301e-06, -1.89489258e-08, // wvp^0 e_mrt^1 va^4
+    8.15300114e-08, // wvp^0 e_mrt^1 va^5
+    7.55043090e-04, -5.65095215e-05, -4.52166564e-07, 2.46688878e-08, 2.42674348e-10, // wvp^0 e_mrt^2 va^0
+    1.54547250e-04, 5.24110970e-06, -8.75874982e-08, -1.50743064e-09, // wvp^0 e_mrt^2 va^1
+    -1.56236307e-05, -1.33895614e-07, 2.49709824e-09, // wvp^0 e_mrt^2 va^2
+    6.51711721e-07, 1.94960053e-09, // wvp^0 e_mrt^2 va^3
+    -1.00361113e-08, // wvp^0 e_mrt^2 va^4
+    -1.21206673e-05, -2.18203660e-07, 7.51269482e-09, 9.79063848e-11, // wvp^0 e_mrt^3 va^0
+    1.25006734e-06, -1.81584736e-09, -3.52197671e-10, // wvp^0 e_mrt^3 va^1
+    -3.36514630e-08, 1.35908359e-10, // wvp^0 e_mrt^3 va^2
+    4.17032620e-10, // wvp^0 e_mrt^3 va^3
+    -1.30369025e-09, 4.13908461e-10, 9.22652254e-12, // wvp^0 e_mrt^4 va^0
+    -5.08220384e-09, -2.24730961e-11, // wvp^0 e_mrt^4 va^1
+    1.17139133e-10, // wvp^0 e_mrt^4 va^2
+    6.62154879e-10, 4.03863260e-13, // wvp^0 e_mrt^5 va^0
+    1.95087203e-12, // wvp^0 e_mrt^5 va^1
+    -4.73602469e-12, // wvp^0 e_mrt^6 va^0
+    5.12733497e00, -3.12788561e-01, -1.96701861e-02, 9.99690870e-04, 9.51738512e-06, -4.66426341e-07, // wvp^1 e_mrt^0 va^0
+    5.48050612e-01, -3.30552823e-03, -1.64119440e-03, -5.16670694e-06, 9.52692432e-07, // wvp^1 e_mrt^0 va^1
+    -4.29223622e-02, 5.00845667e-03, 1.00601257e-06, -1.81748644e-06, // wvp^1 e_mrt^0 va^2
+    -1.25813502e-03, -1.79330391e-04, 2.34994441e-06, // wvp^1 e_mrt^0 va^3
+    1.29735808e-04, 1.29064870e-06, // wvp^1 e_mrt^0 va^4
+    -2.28558686e-06, // wvp^1 e_mrt^0 va^5
+    -3.69476348e-02, 1.62325322e-03, -3.14279680e-05, 2.59835559e-06, -4.77136523e-08, // wvp^1 e_mrt^1 va^0
+    8.64203390e-03, -6.87405181e-04, -9.13863872e-06, 5.15916806e-07, // wvp^1 e_mrt^1 va^1
+    -3.59217476e-05, 3.28696511e-05, -7.10542454e-07, // wvp^1 e_mrt^1 va^2
+    -1.24382300e-05, -7.38584400e-09, // wvp^1 e_mrt^1 va^3
+    2.20609296e-07, // wvp^1 e_mrt^1 va^4
+    -7.32469180e-04, -1.87381964e-05, 4.80925239e-06, -8.75492040e-08, // wvp^1 e_mrt^2 va^0
+    2.77862930e-05, -5.06004592e-06, 1.14325367e-07, // wvp^1 e_mrt^2 va^1
+    2.53016723e-06, -1.72857035e-08, // wvp^1 e_mrt^2 va^2
+    -3.95079398e-08, // wvp^1 e_mrt^2 va^3
+    -3.59413173e-07, 7.04388046e-07, -1.89309167e-08, // wvp^1 e_mrt^3 va^0
+    -4.79768731e-07, 7.96079978e-09, // wvp^1 e_mrt^3 va^1
+    1.62897058e-09, // wvp^1 e_mrt^3 va^2
+    3.94367674e-08, -1.18566247e-09, // wvp^1 e_mrt^4 va^0
+    3.34678041e-10, // wvp^1 e_mrt^4 va^1
+    -1.15606447e-10, // wvp^1 e_mrt^5 va^0
+    -2.80626406e00, 5.48712484e-01, -3.99428410e-03, -9.54009191e-04, 1.93090978e-05, // wvp^2 e_mrt^0 va^0
+    -3.08806365e-01, 1.16952364e-02, 4.95271903e-04, -1.90710882e-05, // wvp^2 e_mrt^0 va^1
+    2.10787756e-03, -6.98445738e-04, 2.30109073e-05, // wvp^2 e_mrt^0 va^2
+    4.17856590e-04, -1.27043871e-05, // wvp^2 e_mrt^0 va^3
+    -3.04620472e-06, // wvp^2 e_mrt^0 va^4
+    5.14507424e-02, -4.32510997e-03, 8.99281156e-05, -7.14663943e-07, // wvp^2 e_mrt^1 va^0
+    -2.66016305e-04, 2.63789586e-04, -7.01199003e-06, // wvp^2 e_mrt^1 va^1
+    -1.06823306e-04, 3.61341136e-06, // wvp^2 e_mrt^1 va^2
+    2.29748967e-07, // wvp^2 e_mrt^1 va^3
+    3.04788893e-04, -6.42070836e-05, 1.16257971e-06, // wvp^2 e_mrt^2 va^0
+    7.68023384e-06, -5.47446896e-07, // wvp^2 e_mrt^2 va^1
+    -3.59937910e-08, // wvp^2 e_mrt^2 va^2
+    -4.36497725e-06, 1.68737969e-07, // wvp^2 e_mrt^3 va^0
+    2.67489271e-08, // wvp^2 e_mrt^3 va^1
+    3.23926897e-09, // wvp^2 e_mrt^4 va^0
+    -3.53874123e-02, -2.21201190e-01, 1.55126038e-02, -2.63917279e-04, // wvp^3 e_mrt^0 va^0
+    4.53433455e-02, -4.32943862e-03, 1.45389826e-04, // wvp^3 e_mrt^0 va^1
+    2.17508610e-04, -6.66724702e-05, // wvp^3 e_mrt^0 va^2
+    3.33217140e-05, // wvp^3 e_mrt^0 va^3
+    -2.26921615e-03, 3.80261982e-04, -5.45314314e-09, // wvp^3 e_mrt^1 va^0
+    -7.96355448e-04, 2.53458034e-05, // wvp^3 e_mrt^1 va^1
+    -6.31223658e-06, // wvp^3 e_mrt^1 va^2
+    3.02122035e-04, -4.77403547e-06, // wvp^3 e_mrt^2 va^0
+    1.73825715e-06, // wvp^3 e_mrt^2 va^1
+    -4.09087898e-07, // wvp^3 e_mrt^3 va^0
+    6.14155345e-01, -6.16755931e-02, 1.33374846e-03, // wvp^4 e_mrt^0 va^0
+    3.55375387e-03, -5.13027851e-04, // wvp^4 e_mrt^0 va^1
+    1.02449757e-04, // wvp^4 e_mrt^0 va^2
+    -1.48526421e-03, -4.11469183e-05, // wvp^4 e_mrt^1 va^0
+    -6.80434415e-06, // wvp^4 e_mrt^1 va^1
+    -9.77675906e-06, // wvp^4 e_mrt^2 va^0
+    8.82773108e-02, -3.01859306e-03, // wvp^5 e_mrt^0 va^0
+    1.04452989e-03, // wvp^5 e_mrt^0 va^1
+    2.47090539e-04, // wvp^5 e_mrt^1 va^0
+    1.48348065e-03, // wvp^6 e_mrt^0 va^0
+];
+
+/// Evaluates `c[0] + c[1] x + c[2] x^2 + ...` by Horner's scheme.
+fn horner(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
 }
 
 /// Helper function to calculate the UTCI polynomial approximation.
 ///
-/// Where `t2m` is the 2m temperature in Kelvin.
+/// Where `t2m` is the 2m temperature in Celsius.
 ///
-/// Where `mrt` is the mean radiant temperature in Kelvin.
+/// Where `mrt` is the mean radiant temperature in Celsius.
 ///
 /// Where `va` is the wind speed at 10 meters in m/s.
 ///
 /// Where `wvp` is the water vapour pressure in kPa.
 ///
-/// The return value is UTCI in Kelvin.
+/// The return value is UTCI in Celsius.
+///
+/// Each group of `t2m` terms in [`UTCI_COEFFICIENTS`] is evaluated by Horner's scheme and scaled by
+/// the running power of `va`, `e_mrt` and `wvp`, rather than forming every cross-product of powers.
 ///
 /// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
 fn calculate_utci_polynomial(t2m: f64, mrt: f64, va: f64, wvp: f64) -> f64 {
     let e_mrt = mrt - t2m;
 
-    let t2m2 = t2m * t2m;
-    let t2m3 = t2m2 * t2m;
-    let t2m4 = t2m3 * t2m;
-    let t2m5 = t2m4 * t2m;
-    let t2m6 = t2m5 * t2m;
-
-    let va2 = va * va;
-    let va3 = va2 * va;
-    let va4 = va3 * va;
-    let va5 = va4 * va;
-    let va6 = va5 * va;
-
-    let e_mrt2 = e_mrt * e_mrt;
-    let e_mrt3 = e_mrt2 * e_mrt;
-    let e_mrt4 = e_mrt3 * e_mrt;
-    let e_mrt5 = e_mrt4 * e_mrt;
-    let e_mrt6 = e_mrt5 * e_mrt;
-
-    let wvp2 = wvp * wvp;
-    let wvp3 = wvp2 * wvp;
-    let wvp4 = wvp3 * wvp;
-    let wvp5 = wvp4 * wvp;
-    let wvp6 = wvp5 * wvp;
-
-    let varh2 = va * wvp2;
-    let va2_rh = va2 * wvp;
-    let va2_e_mrt = va2 * e_mrt;
-    let e_mrt_rh = e_mrt * wvp;
-    let e_mrt_rh2 = e_mrt * wvp2;
-    let e_mrt2_rh = e_mrt2 * wvp;
-    let e_mrt2_rh2 = e_mrt2 * wvp2;
-    let e_mrt_rh3 = e_mrt * wvp3;
-    let va_e_mrt = va * e_mrt;
-    let va_e_mrt2 = va * e_mrt2;
-    let va_rh = va * wvp;
-    let t2m_va = t2m * va;
-    let e_mrt3_rh = e_mrt3 * wvp;
-    let e_mrt4_rh = e_mrt4 * wvp;
-
-    let utci = t2m
-        + 6.07562052e-01
-        + -2.27712343e-02 * t2m
-        + 8.06470249e-04 * t2m2
-        + -1.54271372e-04 * t2m3
-        + -3.24651735e-06 * t2m4
-        + 7.32602852e-08 * t2m5
-        + 1.35959073e-09 * t2m6
-        + -2.25836520e00 * va
-        + 8.80326035e-02 * t2m * va
-        + 2.16844454e-03 * t2m2 * va
-        + -1.53347087e-05 * t2m3 * va
-        + -5.72983704e-07 * t2m4 * va
-        + -2.55090145e-09 * t2m5 * va
-        + -7.51269505e-01 * va2
-        + -4.08350271e-03 * t2m * va2
-        + -5.21670675e-05 * t2m2 * va2
-        + 1.94544667e-06 * t2m3 * va2
-        + 1.14099531e-08 * t2m4 * va2
-        + 1.58137256e-01 * va3
-        + -6.57263143e-05 * t2m * va3
-        + 2.22697524e-07 * t2m2 * va3
-        + -4.16117031e-08 * t2m3 * va3
-        + -1.27762753e-02 * va4
-        + 9.66891875e-06 * t2m * va4
-        + 2.52785852e-09 * t2m2 * va4
-        + 4.56306672e-04 * va5
-        + -1.74202546e-07 * t2m * va5
-        + -5.91491269e-06 * va6
-        + 3.98374029e-01 * e_mrt
-        + 1.83945314e-04 * t2m * e_mrt
-        + -1.73754510e-04 * t2m2 * e_mrt
-        + -7.60781159e-07 * t2m3 * e_mrt
-        + 3.77830287e-08 * t2m4 * e_mrt
-        + 5.43079673e-10 * t2m5 * e_mrt
-        + -2.00518269e-02 * va_e_mrt
-        + 8.92859837e-04 * t2m * va_e_mrt
-        + 3.45433048e-06 * t2m2 * va_e_mrt
-        + -3.77925774e-07 * t2m3 * va_e_mrt
-        + -1.69699377e-09 * t2m4 * va_e_mrt
-        + 1.69992415e-04 * va2_e_mrt
-        + -4.99204314e-05 * t2m * va2_e_mrt
-        + 2.47417178e-07 * t2m2 * va2_e_mrt
-        + 1.07596466e-08 * t2m3 * va2_e_mrt
-        + 8.49242932e-05 * va3 * e_mrt
-        + 1.35191328e-06 * t2m * va3 * e_mrt
-        + -6.21531254e-09 * t2m2 * va3 * e_mrt
-        + -4.99410301e-06 * va4 * e_mrt
-        + -1.89489258e-08 * t2m * va4 * e_mrt
-        + 8.15300114e-08 * va5 * e_mrt
-        + 7.55043090e-04 * e_mrt2
-        + -5.65095215e-05 * t2m * e_mrt2
-        + -4.52166564e-07 * t2m2 * e_mrt2
-        + 2.46688878e-08 * t2m3 * e_mrt2
-        + 2.42674348e-10 * t2m4 * e_mrt2
-        + 1.54547250e-04 * va_e_mrt2
-        + 5.24110970e-06 * t2m * va_e_mrt2
-        + -8.75874982e-08 * t2m2 * va_e_mrt2
-        + -1.50743064e-09 * t2m3 * va_e_mrt2
-        + -1.56236307e-05 * va2 * e_mrt2
-        + -1.33895614e-07 * t2m * va2 * e_mrt2
-        + 2.49709824e-09 * t2m2 * va2 * e_mrt2
-        + 6.51711721e-07 * va3 * e_mrt2
-        + 1.94960053e-09 * t2m * va3 * e_mrt2
-        + -1.00361113e-08 * va4 * e_mrt2
-        + -1.21206673e-05 * e_mrt3
-        + -2.18203660e-07 * t2m * e_mrt3
-        + 7.51269482e-09 * t2m2 * e_mrt3
-        + 9.79063848e-11 * t2m3 * e_mrt3
-        + 1.25006734e-06 * va * e_mrt3
-        + -1.81584736e-09 * t2m_va * e_mrt3
-        + -3.52197671e-10 * t2m2 * va * e_mrt3
-        + -3.36514630e-08 * va2 * e_mrt3
-        + 1.35908359e-10 * t2m * va2 * e_mrt3
-        + 4.17032620e-10 * va3 * e_mrt3
-        + -1.30369025e-09 * e_mrt4
-        + 4.13908461e-10 * t2m * e_mrt4
-        + 9.22652254e-12 * t2m2 * e_mrt4
-        + -5.08220384e-09 * va * e_mrt4
-        + -2.24730961e-11 * t2m_va * e_mrt4
-        + 1.17139133e-10 * va2 * e_mrt4
-        + 6.62154879e-10 * e_mrt5
-        + 4.03863260e-13 * t2m * e_mrt5
-        + 1.95087203e-12 * va * e_mrt5
-        + -4.73602469e-12 * e_mrt6
-        + 5.12733497e00 * wvp
-        + -3.12788561e-01 * t2m * wvp
-        + -1.96701861e-02 * t2m2 * wvp
-        + 9.99690870e-04 * t2m3 * wvp
-        + 9.51738512e-06 * t2m4 * wvp
-        + -4.66426341e-07 * t2m5 * wvp
-        + 5.48050612e-01 * va_rh
-        + -3.30552823e-03 * t2m * va_rh
-        + -1.64119440e-03 * t2m2 * va_rh
-        + -5.16670694e-06 * t2m3 * va_rh
-        + 9.52692432e-07 * t2m4 * va_rh
-        + -4.29223622e-02 * va2_rh
-        + 5.00845667e-03 * t2m * va2_rh
-        + 1.00601257e-06 * t2m2 * va2_rh
-        + -1.81748644e-06 * t2m3 * va2_rh
-        + -1.25813502e-03 * va3 * wvp
-        + -1.79330391e-04 * t2m * va3 * wvp
-        + 2.34994441e-06 * t2m2 * va3 * wvp
-        + 1.29735808e-04 * va4 * wvp
-        + 1.29064870e-06 * t2m * va4 * wvp
-        + -2.28558686e-06 * va5 * wvp
-        + -3.69476348e-02 * e_mrt_rh
-        + 1.62325322e-03 * t2m * e_mrt_rh
-        + -3.14279680e-05 * t2m2 * e_mrt_rh
-        + 2.59835559e-06 * t2m3 * e_mrt_rh
-        + -4.77136523e-08 * t2m4 * e_mrt_rh
-        + 8.64203390e-03 * va * e_mrt_rh
-        + -6.87405181e-04 * t2m_va * e_mrt_rh
-        + -9.13863872e-06 * t2m2 * va * e_mrt_rh
-        + 5.15916806e-07 * t2m3 * va * e_mrt_rh
-        + -3.59217476e-05 * va2 * e_mrt_rh
-        + 3.28696511e-05 * t2m * va2 * e_mrt_rh
-        + -7.10542454e-07 * t2m2 * va2 * e_mrt_rh
-        + -1.24382300e-05 * va3 * e_mrt_rh
-        + -7.38584400e-09 * t2m * va3 * e_mrt_rh
-        + 2.20609296e-07 * va4 * e_mrt_rh
-        + -7.32469180e-04 * e_mrt2_rh
-        + -1.87381964e-05 * t2m * e_mrt2_rh
-        + 4.80925239e-06 * t2m2 * e_mrt2_rh
-        + -8.75492040e-08 * t2m3 * e_mrt2_rh
-        + 2.77862930e-05 * va * e_mrt2_rh
-        + -5.06004592e-06 * t2m_va * e_mrt2_rh
-        + 1.14325367e-07 * t2m2 * va * e_mrt2_rh
-        + 2.53016723e-06 * va2 * e_mrt2_rh
-        + -1.72857035e-08 * t2m * va2 * e_mrt2_rh
-        + -3.95079398e-08 * va3 * e_mrt2_rh
-        + -3.59413173e-07 * e_mrt3_rh
-        + 7.04388046e-07 * t2m * e_mrt3_rh
-        + -1.89309167e-08 * t2m2 * e_mrt3_rh
-        + -4.79768731e-07 * va * e_mrt3_rh
-        + 7.96079978e-09 * t2m_va * e_mrt3_rh
-        + 1.62897058e-09 * va2 * e_mrt3_rh
-        + 3.94367674e-08 * e_mrt4_rh
-        + -1.18566247e-09 * t2m * e_mrt4_rh
-        + 3.34678041e-10 * va * e_mrt4_rh
-        + -1.15606447e-10 * e_mrt5 * wvp
-        + -2.80626406e00 * wvp2
-        + 5.48712484e-01 * t2m * wvp2
-        + -3.99428410e-03 * t2m2 * wvp2
-        + -9.54009191e-04 * t2m3 * wvp2
-        + 1.93090978e-05 * t2m4 * wvp2
-        + -3.08806365e-01 * varh2
-        + 1.16952364e-02 * t2m * varh2
-        + 4.95271903e-04 * t2m2 * varh2
-        + -1.90710882e-05 * t2m3 * varh2
-        + 2.10787756e-03 * va2 * wvp2
-        + -6.98445738e-04 * t2m * va2 * wvp2
-        + 2.30109073e-05 * t2m2 * va2 * wvp2
-        + 4.17856590e-04 * va3 * wvp2
-        + -1.27043871e-05 * t2m * va3 * wvp2
-        + -3.04620472e-06 * va4 * wvp2
-        + 5.14507424e-02 * e_mrt_rh2
-        + -4.32510997e-03 * t2m * e_mrt_rh2
-        + 8.99281156e-05 * t2m2 * e_mrt_rh2
-        + -7.14663943e-07 * t2m3 * e_mrt_rh2
-        + -2.66016305e-04 * va * e_mrt_rh2
-        + 2.63789586e-04 * t2m_va * e_mrt_rh2
-        + -7.01199003e-06 * t2m2 * va * e_mrt_rh2
-        + -1.06823306e-04 * va2 * e_mrt_rh2
-        + 3.61341136e-06 * t2m * va2 * e_mrt_rh2
-        + 2.29748967e-07 * va3 * e_mrt_rh2
-        + 3.04788893e-04 * e_mrt2_rh2
-        + -6.42070836e-05 * t2m * e_mrt2_rh2
-        + 1.16257971e-06 * t2m2 * e_mrt2_rh2
-        + 7.68023384e-06 * va * e_mrt2_rh2
-        + -5.47446896e-07 * t2m_va * e_mrt2_rh2
-        + -3.59937910e-08 * va2 * e_mrt2_rh2
-        + -4.36497725e-06 * e_mrt3 * wvp2
-        + 1.68737969e-07 * t2m * e_mrt3 * wvp2
-        + 2.67489271e-08 * va * e_mrt3 * wvp2
-        + 3.23926897e-09 * e_mrt4 * wvp2
-        + -3.53874123e-02 * wvp3
-        + -2.21201190e-01 * t2m * wvp3
-        + 1.55126038e-02 * t2m2 * wvp3
-        + -2.63917279e-04 * t2m3 * wvp3
-        + 4.53433455e-02 * va * wvp3
-        + -4.32943862e-03 * t2m_va * wvp3
-        + 1.45389826e-04 * t2m2 * va * wvp3
-        + 2.17508610e-04 * va2 * wvp3
-        + -6.66724702e-05 * t2m * va2 * wvp3
-        + 3.33217140e-05 * va3 * wvp3
-        + -2.26921615e-03 * e_mrt_rh3
-        + 3.80261982e-04 * t2m * e_mrt_rh3
-        + -5.45314314e-09 * t2m2 * e_mrt_rh3
-        + -7.96355448e-04 * va * e_mrt_rh3
-        + 2.53458034e-05 * t2m_va * e_mrt_rh3
-        + -6.31223658e-06 * va2 * e_mrt_rh3
-        + 3.02122035e-04 * e_mrt2 * wvp3
-        + -4.77403547e-06 * t2m * e_mrt2 * wvp3
-        + 1.73825715e-06 * va * e_mrt2 * wvp3
-        + -4.09087898e-07 * e_mrt3 * wvp3
-        + 6.14155345e-01 * wvp4
-        + -6.16755931e-02 * t2m * wvp4
-        + 1.33374846e-03 * t2m2 * wvp4
-        + 3.55375387e-03 * va * wvp4
-        + -5.13027851e-04 * t2m_va * wvp4
-        + 1.02449757e-04 * va2 * wvp4
-        + -1.48526421e-03 * e_mrt * wvp4
-        + -4.11469183e-05 * t2m * e_mrt * wvp4
-        + -6.80434415e-06 * va * e_mrt * wvp4
-        + -9.77675906e-06 * e_mrt2 * wvp4
-        + 8.82773108e-02 * wvp5
-        + -3.01859306e-03 * t2m * wvp5
-        + 1.04452989e-03 * va * wvp5
-        + 2.47090539e-04 * e_mrt * wvp5
-        + 1.48348065e-03 * wvp6;
-
+    let mut coefficients = UTCI_COEFFICIENTS.as_slice();
+    let mut utci = t2m;
+    let mut wvp_k = 1.0;
+    for k in 0..=6 {
+        let mut e_mrt_j = wvp_k;
+        for j in 0..=6 - k {
+            let mut va_i = e_mrt_j;
+            for i in 0..=6 - k - j {
+                let (t2m_coefficients, rest) = coefficients.split_at(7 - k - j - i);
+                utci += va_i * horner(t2m, t2m_coefficients);
+                coefficients = rest;
+                va_i *= va;
+            }
+            e_mrt_j *= e_mrt;
+        }
+        wvp_k *= wvp;
+    }
     utci
 }
 
-/// Calculates the Universal Thermal Climate Index (UTCI).
-///
-/// Where `t2_k` is the 2m temperature in Kelvin.
-///
+/// Evaluates the derivative of `c[0] + c[1] x + c[2] x^2 + ...` by Horner's scheme.
+fn horner_derivative(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(0.0, |acc, (n, c)| acc * x + n as f64 * c)
+}
+
+/// The derivative of `x^n`, kept finite at `x = 0` when `n = 0`.
+fn power_derivative(x: f64, n: usize) -> f64 {
+    if n == 0 {
+        0.0
+    } else {
+        n as f64 * x.powi(n as i32 - 1)
+    }
+}
+
+/// The partial derivatives of [`calculate_utci_polynomial`] with respect to `t2m`, `va`, `mrt` and
+/// `wvp`, in the units of its arguments.
+fn utci_polynomial_gradient(t2m: f64, mrt: f64, va: f64, wvp: f64) -> (f64, f64, f64, f64) {
+    let e_mrt = mrt - t2m;
+
+    let mut coefficients = UTCI_COEFFICIENTS.as_slice();
+    let (mut d_t2m, mut d_va, mut d_e_mrt, mut d_wvp) = (1.0, 0.0, 0.0, 0.0);
+    for k in 0..=6 {
+        for j in 0..=6 - k {
+            for i in 0..=6 - k - j {
+                let (t2m_coefficients, rest) = coefficients.split_at(7 - k - j - i);
+                coefficients = rest;
+
+                let p = horner(t2m, t2m_coefficients);
+                let (wvp_k, e_mrt_j, va_i) =
+                    (wvp.powi(k as i32), e_mrt.powi(j as i32), va.powi(i as i32));
+                d_t2m += wvp_k * e_mrt_j * va_i * horner_derivative(t2m, t2m_coefficients);
+                d_va += wvp_k * e_mrt_j * power_derivative(va, i) * p;
+                d_e_mrt += wvp_k * power_derivative(e_mrt, j) * va_i * p;
+                d_wvp += power_derivative(wvp, k) * e_mrt_j * va_i * p;
+            }
+        }
+    }
+
+    // e_mrt = mrt - t2m, so raising t2m lowers e_mrt.
+    (d_t2m - d_e_mrt, d_va, d_e_mrt, d_wvp)
+}
+
+/// Calculates the partial derivatives of the Universal Thermal Climate Index (UTCI).
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `wvp` is the water vapour pressure in kPa, as from [`calculate_water_vapour_pressure_kpa`].
+///
+/// The return value is `(d_dt2m, d_dva, d_dmrt, d_dwvp)`, the partial derivatives of
+/// [`calculate_utci`] with `eh_pa = 10 wvp`, in K K-1, K (m/s)-1, K K-1 and K kPa-1. They are
+/// computed analytically from the polynomial, so are exact up to rounding. Air temperature enters
+/// both directly and through the mean radiant temperature offset `mrt - t2_k`, so `d_dt2m` is the
+/// total effect of warming the air with `mrt` held fixed. Multiplying each partial by a change in
+/// its input attributes a small change in UTCI among the drivers. Negative wind speeds are treated
+/// as calm (0 m/s), as in [`calculate_utci`], so the partials are those at calm.
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_gradient(t2_k: f64, va: f64, mrt: f64, wvp: f64) -> (f64, f64, f64, f64) {
+    let va = calm_if_negative(va);
+    utci_polynomial_gradient(kelvin_to_celsius(t2_k), kelvin_to_celsius(mrt), va, wvp)
+}
+
+/// Calculates water vapour pressure in kPa, as used by the UTCI polynomial.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is the water vapour pressure in kPa. Note that the rest of the crate works in
+/// hPa; this is the intermediate passed to the Bröde et al. (2012) polynomial by [`calculate_utci`].
+pub fn calculate_water_vapour_pressure_kpa(t2_k: f64, td_k: f64) -> f64 {
+    let rh_pc = calculate_relative_humidity_percent(t2_k, td_k);
+    let eh_pa = relative_humidity_to_vapour_pressure(t2_k, rh_pc);
+    eh_pa / 10.0
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI).
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
 /// Where `va` is the wind speed at 10 meters in m/s.
 ///
 /// Where `mrt` is the mean radiant temperature in Kelvin.
@@ -480,29 +1077,239 @@ fn calculate_utci_polynomial(t2m: f64, mrt: f64, va: f64, wvp: f64) -> f64 {
 ///
 /// The return value is UTCI in Kelvin.
 ///
+/// Negative wind speeds are treated as calm (0 m/s).
+///
 /// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
 pub fn calculate_utci(t2_k: f64, va: f64, mrt: f64, td_k: Option<f64>, eh_pa: Option<f64>) -> f64 {
+    trace_span!("calculate_utci", t2_k, va, mrt);
+    let va = calm_if_negative(va);
     let wvp: f64;
 
     if let Some(eh_pa) = eh_pa {
         wvp = eh_pa / 10.0; // water vapour pressure in kPa
+    } else if let Some(td_k) = td_k {
+        wvp = calculate_water_vapour_pressure_kpa(t2_k, td_k);
     } else {
-        if let Some(td_k) = td_k {
-            let rh_pc = calculate_relative_humidity_percent(t2_k, td_k);
-            let eh_pa = calculate_saturation_vapour_pressure(t2_k) * rh_pc / 100.0;
-            wvp = eh_pa / 10.0; // water vapour pressure in kPa
-        } else {
-            panic!("Missing input ehPa or td_k");
-        }
+        panic!("Missing input ehPa or td_k");
     }
 
     let t2_c = kelvin_to_celsius(t2_k);
     let mrt_c = kelvin_to_celsius(mrt);
 
     let utci = calculate_utci_polynomial(t2_c, mrt_c, va, wvp);
-    let utci_k = celsius_to_kelvin(utci);
+    trace_values!(wvp, utci_c = utci);
+    celsius_to_kelvin(utci)
+}
+
+/// A single source of humidity for [`calculate_utci_humidity`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HumidityInput {
+    /// 2m dew point temperature in Kelvin.
+    DewPoint(f64),
+    /// Water vapour pressure in hPa.
+    VapourPressureHpa(f64),
+    /// Relative humidity percentage.
+    RelativeHumidity(f64),
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) from any one source of humidity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `humidity` is the dew point, vapour pressure or relative humidity.
+///
+/// The return value is UTCI in Kelvin. This is [`calculate_utci`] without the possibility of
+/// passing no humidity. Relative humidity is converted with [`relative_humidity_to_vapour_pressure`],
+/// the same conversion used for a dew point.
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_humidity(t2_k: f64, va: f64, mrt: f64, humidity: HumidityInput) -> f64 {
+    match humidity {
+        HumidityInput::DewPoint(td_k) => calculate_utci(t2_k, va, mrt, Some(td_k), None),
+        HumidityInput::VapourPressureHpa(eh_pa) => calculate_utci(t2_k, va, mrt, None, Some(eh_pa)),
+        HumidityInput::RelativeHumidity(rh) => {
+            let eh_pa = relative_humidity_to_vapour_pressure(t2_k, rh);
+            calculate_utci(t2_k, va, mrt, None, Some(eh_pa))
+        }
+    }
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI), rejecting inputs outside the domain of the
+/// polynomial.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is an optional 2m dew point temperature in Kelvin.
+///
+/// Where `eh_pa` is an optional water vapour pressure in hPa.
+///
+/// The return value is UTCI in Kelvin, as from [`calculate_utci`], or a
+/// [`ThermofeelError::OutOfValidRange`] if the inputs leave the range the polynomial was fitted
+/// over. Bröde et al. (2012) give this range as:
+///
+/// - air temperature from -50°C to +50°C
+/// - mean radiant temperature from 30°C below to 70°C above air temperature
+/// - wind speed at 10 meters from 0.5 m/s to 17 m/s
+/// - water vapour pressure up to 5 kPa
+///
+/// Outside it the regression can produce physically meaningless values. Negative wind speeds are
+/// below this range, so they are rejected rather than treated as calm. The error names the input
+/// that was checked: `t2_c` is `t2_k` in Celsius, `mrt - t2_k` the radiant excess in K, `va` the
+/// wind speed and `wvp` the water vapour pressure in kPa.
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_checked(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    td_k: Option<f64>,
+    eh_pa: Option<f64>,
+) -> Result<f64, ThermofeelError> {
+    let wvp = match (eh_pa, td_k) {
+        (Some(eh_pa), _) => eh_pa / 10.0,
+        (None, Some(td_k)) => calculate_water_vapour_pressure_kpa(t2_k, td_k),
+        (None, None) => return Err(ThermofeelError::MissingHumidityInput),
+    };
+
+    check_utci_domain(t2_k, va, mrt, wvp)?;
+    Ok(calculate_utci(t2_k, va, mrt, None, Some(wvp * 10.0)))
+}
+
+/// Checks the inputs of [`calculate_utci`], with `wvp` in kPa, against the range the polynomial was
+/// fitted over.
+fn check_utci_domain(t2_k: f64, va: f64, mrt: f64, wvp: f64) -> Result<(), ThermofeelError> {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let d_mrt = mrt - t2_k;
+
+    let check = |name, value: f64, min: f64, max: f64| {
+        if (min..=max).contains(&value) {
+            Ok(())
+        } else {
+            Err(ThermofeelError::OutOfValidRange {
+                name,
+                value,
+                min,
+                max,
+            })
+        }
+    };
+    check("t2_c", t2_c, -50.0, 50.0)?;
+    check("mrt - t2_k", d_mrt, -30.0, 70.0)?;
+    check("va", va, 0.5, 17.0)?;
+    check("wvp", wvp, f64::NEG_INFINITY, 5.0)
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) and whether its inputs are in range.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `td_k` is an optional 2m dew point temperature in Kelvin.
+///
+/// Where `eh_pa` is an optional water vapour pressure in hPa.
+///
+/// The return value is UTCI in Kelvin, exactly as from [`calculate_utci`], and `true` if the inputs
+/// are inside the range the polynomial was fitted over. The range is that of
+/// [`calculate_utci_checked`]: air temperature from -50°C to +50°C, mean radiant temperature from
+/// 30°C below to 70°C above air temperature, wind speed from 0.5 m/s to 17 m/s and water vapour
+/// pressure up to 5 kPa. Outside it the value is an extrapolation, which grid processing can keep
+/// and mask afterwards rather than lose. NaN inputs are reported as out of range.
+///
+/// # Panics
+///
+/// Panics if both `td_k` and `eh_pa` are `None`, as [`calculate_utci`].
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_with_validity(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    td_k: Option<f64>,
+    eh_pa: Option<f64>,
+) -> (f64, bool) {
+    let utci = calculate_utci(t2_k, va, mrt, td_k, eh_pa);
+    let wvp = match (eh_pa, td_k) {
+        (Some(eh_pa), _) => eh_pa / 10.0,
+        (None, Some(td_k)) => calculate_water_vapour_pressure_kpa(t2_k, td_k),
+        (None, None) => unreachable!("calculate_utci panics without humidity"),
+    };
+    (utci, check_utci_domain(t2_k, va, mrt, wvp).is_ok())
+}
+
+/// Converts specific humidity to water vapour pressure.
+///
+/// Where `q` is the specific humidity in kg kg-1.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the water vapour pressure in hPa, `e = q p / (0.622 + 0.378 q)`. Here 0.622
+/// is [`constants::MOLAR_MASS_RATIO`], the ratio of the molar masses of water vapour and dry air,
+/// and 0.378 is `1 - 0.622`. This is the inverse of `q = 0.622 e / (p - 0.378 e)`.
+pub fn vapour_pressure_from_specific_humidity(q: f64, pressure_hpa: f64) -> f64 {
+    q * pressure_hpa / (MOLAR_MASS_RATIO + 0.378 * q)
+}
 
-    return utci_k;
+/// Calculates the Universal Thermal Climate Index (UTCI) from specific humidity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `q` is the 2m specific humidity in kg kg-1.
+///
+/// Where `pressure_hpa` is the surface air pressure in hPa.
+///
+/// The return value is UTCI in Kelvin.
+///
+/// The specific humidity is converted to water vapour pressure with
+/// [`vapour_pressure_from_specific_humidity`] before calling [`calculate_utci`].
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_from_specific_humidity(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    q: f64,
+    pressure_hpa: f64,
+) -> f64 {
+    let eh_pa = vapour_pressure_from_specific_humidity(q, pressure_hpa);
+    calculate_utci(t2_k, va, mrt, None, Some(eh_pa))
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) from a black globe temperature.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `globe_k` is the black globe temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// The return value is UTCI in Kelvin, from [`calculate_utci`] with the mean radiant temperature
+/// from [`calculate_mrt_from_bgt`]. That assumes a standard 0.15 m globe with emissivity 0.95 under
+/// forced convection, with the wind scaled from 10 meters to the globe at 1.1 meters; for other
+/// globes, derive the mean radiant temperature with [`calculate_mrt_from_globe`] and call
+/// [`calculate_utci`] directly. Errors in the globe reading are amplified in the mean radiant
+/// temperature, increasingly so as the wind speed rises.
+///
+/// Reference: Brode et al. (2012) [https://doi.org/10.1007/s00484-011-0454-1](https://doi.org/10.1007/s00484-011-0454-1)
+pub fn calculate_utci_from_globe(t2_k: f64, va: f64, globe_k: f64, td_k: f64) -> f64 {
+    let mrt = calculate_mrt_from_bgt(t2_k, globe_k, va);
+    calculate_utci(t2_k, va, mrt, Some(td_k), None)
 }
 
 /// Calculates Wet Bulb Globe Temperature (WBGT) using a simplified algorithm.
@@ -513,18 +1320,49 @@ pub fn calculate_utci(t2_k: f64, va: f64, mrt: f64, td_k: Option<f64>, eh_pa: Op
 ///
 /// The return value is the Wet Bulb Globe Temperature in Kelvin.
 ///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
 /// Reference: ACSM (1984) [https://doi.org/10.1080/00913847.1984.11701899](https://doi.org/10.1080/00913847.1984.11701899)
 ///
 /// See also: [http://www.bom.gov.au/info/thermal_stress/#approximation](http://www.bom.gov.au/info/thermal_stress/#approximation)
 ///
 /// See also: [https://www.jstage.jst.go.jp/article/indhealth/50/4/50_MS1352/_pdf](https://www.jstage.jst.go.jp/article/indhealth/50/4/50_MS1352/_pdf)
 pub fn calculate_wbgt_simple(t2_k: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
     let t2_c = kelvin_to_celsius(t2_k);
     let e = calculate_nonsaturation_vapour_pressure(t2_k, rh);
     let wbgt = 0.567 * t2_c + 0.393 * e + 3.94;
-    let wbgt_k = celsius_to_kelvin(wbgt);
+    celsius_to_kelvin(wbgt)
+}
 
-    return wbgt_k;
+/// Calculates Wet Bulb Globe Temperature (WBGT) using a simplified algorithm at a given air pressure.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the surface air pressure in hPa.
+///
+/// The return value is the Wet Bulb Globe Temperature in Kelvin.
+///
+/// The formula of [`calculate_wbgt_simple`] approximates the shade WBGT `0.7 Tw + 0.3 Ta` at sea
+/// level, where pressure enters only through the wet bulb temperature `Tw`. The psychrometric
+/// constant is proportional to pressure, so at altitude the wet bulb sits further below the air
+/// temperature for the same humidity. The result is shifted by 0.7 times the change in the
+/// Davies-Jones wet bulb temperature of [`calculate_wbt_method`] between 1013.25 hPa and
+/// `pressure_hpa`, so both functions agree at sea level, and to within 0.02 K in saturated air. At
+/// 30°C and 50% relative humidity the shift is about -0.57 K at 700 hPa and -1.0 K at 500 hPa.
+///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
+/// Reference: ACSM (1984) [https://doi.org/10.1080/00913847.1984.11701899](https://doi.org/10.1080/00913847.1984.11701899)
+///
+/// See also: Davies-Jones (2008) [https://doi.org/10.1175/2007MWR2224.1](https://doi.org/10.1175/2007MWR2224.1)
+pub fn calculate_wbgt_simple_at_pressure(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let wet_bulb = |p| calculate_wbt_method(t2_k, rh, p, WetBulbMethod::DaviesJones);
+    let shift = 0.7 * (wet_bulb(pressure_hpa) - wet_bulb(STANDARD_PRESSURE_HPA));
+    calculate_wbgt_simple(t2_k, rh) + shift
 }
 
 /// Calculates Wet Bulb Temperature.
@@ -535,8 +1373,11 @@ pub fn calculate_wbgt_simple(t2_k: f64, rh: f64) -> f64 {
 ///
 /// The return value is the wet bulb temperature in Kelvin.
 ///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
 /// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
 pub fn calculate_wbt(t2_k: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
     let t2_c = kelvin_to_celsius(t2_k);
     let tw = t2_c * (0.151977 * (rh + 8.313659).sqrt()).atan() + (t2_c + rh).atan()
         - (rh - 1.676331).atan()
@@ -545,6 +1386,130 @@ pub fn calculate_wbt(t2_k: f64, rh: f64) -> f64 {
     celsius_to_kelvin(tw)
 }
 
+/// A method of calculating Wet Bulb Temperature for [`calculate_wbt_method`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WetBulbMethod {
+    /// The Stull (2011) regression used by [`calculate_wbt`].
+    ///
+    /// It is fitted at sea level pressure for relative humidities between 5% and 99% and
+    /// temperatures between -20°C and 50°C, with errors up to about 1°C. Pressure is ignored.
+    StullRegression,
+    /// The Davies-Jones (2008) method, from the equivalent potential temperature.
+    ///
+    /// It accounts for pressure and agrees with an exact pseudoadiabatic solution to within 0.01 K
+    /// for equivalent potential temperatures up to about 400 K, beyond which (very hot, saturated
+    /// air at low pressure) it degrades. See [`calculate_equivalent_potential_temperature`].
+    DaviesJones,
+}
+
+/// Calculates Wet Bulb Temperature by the chosen method.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// Where `method` selects the calculation, see [`WetBulbMethod`].
+///
+/// The return value is the wet bulb temperature in Kelvin.
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+///
+/// Reference: Davies-Jones (2008) [https://doi.org/10.1175/2007MWR2224.1](https://doi.org/10.1175/2007MWR2224.1)
+pub fn calculate_wbt_method(t2_k: f64, rh: f64, pressure_hpa: f64, method: WetBulbMethod) -> f64 {
+    match method {
+        WetBulbMethod::StullRegression => calculate_wbt(t2_k, rh),
+        WetBulbMethod::DaviesJones => davies_jones_wet_bulb(t2_k, rh, pressure_hpa),
+    }
+}
+
+/// Chooses the [`WetBulbMethod`] suited to the given conditions, as used by [`calculate_wbt_auto`].
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is [`WetBulbMethod::StullRegression`] when all of these hold, bounds included:
+/// relative humidity between 5% and 99%, temperature between -20°C and 50°C, and pressure between
+/// 950 hPa and 1050 hPa. Otherwise, or if any input is NaN, it is [`WetBulbMethod::DaviesJones`].
+/// The first two are the range Stull fitted his regression over. The regression has no pressure
+/// input and was fitted at 1013.25 hPa, so the pressure band keeps it to roughly the sea level
+/// conditions it assumes, within about 500m of altitude.
+pub fn select_wet_bulb_method(t2_k: f64, rh: f64, pressure_hpa: f64) -> WetBulbMethod {
+    let t2_c = kelvin_to_celsius(t2_k);
+    if (5.0..=99.0).contains(&rh)
+        && (-20.0..=50.0).contains(&t2_c)
+        && (950.0..=1050.0).contains(&pressure_hpa)
+    {
+        WetBulbMethod::StullRegression
+    } else {
+        WetBulbMethod::DaviesJones
+    }
+}
+
+/// Calculates Wet Bulb Temperature by the method best suited to the conditions.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the wet bulb temperature in Kelvin, by Stull's regression within its
+/// validated range and by the Davies-Jones solver, which accounts for pressure and refines its
+/// estimate with a Newton step, outside it. [`select_wet_bulb_method`] documents the switch-over.
+/// The two methods differ by up to about 1 K, so the result can jump at the edges of the range.
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+///
+/// Reference: Davies-Jones (2008) [https://doi.org/10.1175/2007MWR2224.1](https://doi.org/10.1175/2007MWR2224.1)
+pub fn calculate_wbt_auto(t2_k: f64, rh: f64, pressure_hpa: f64) -> f64 {
+    let method = select_wet_bulb_method(t2_k, rh, pressure_hpa);
+    calculate_wbt_method(t2_k, rh, pressure_hpa, method)
+}
+
+/// Calculates indoor Wet Bulb Globe Temperature (WBGT) by the method of Bernard.
+///
+/// Where `t2_k` is the air temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `va` is the air speed at the worker in m/s, not the 10m wind speed.
+///
+/// The return value is the wet bulb globe temperature in Kelvin. The natural wet bulb temperature
+/// is estimated from the psychrometric wet bulb `Tpwb` of [`calculate_wbt`] as
+/// `Tnwb = Ta - C (Ta - Tpwb)`, where `C` is 0.85 below 0.03 m/s, `0.96 + 0.069 log10(va)` up to
+/// 3 m/s and 1 above. Without a radiant load the globe is at air temperature, so
+/// `WBGT = 0.7 Tnwb + 0.3 Ta`.
+///
+/// This is only valid indoors or in shade with no significant radiant heat source, where globe and
+/// air temperature are close; use [`calculate_wbgt`] when a mean radiant temperature is available.
+/// Negative air speeds are treated as calm (0 m/s).
+///
+/// Reference: Bernard and Pourmoghani (1999), Prediction of workplace wet bulb global temperature,
+/// Applied Occupational and Environmental Hygiene 14(2)
+///
+/// See also: Lemke and Kjellstrom (2012), Calculating workplace WBGT from meteorological data,
+/// Industrial Health 50(4)
+pub fn calculate_wbgt_bernard(t2_k: f64, rh: f64, va: f64) -> f64 {
+    let va = calm_if_negative(va);
+    let tpwb_k = calculate_wbt(t2_k, rh);
+
+    let c = if va < 0.03 {
+        0.85
+    } else if va > 3.0 {
+        1.0
+    } else {
+        0.96 + 0.069 * va.log10()
+    };
+    let tnwb_k = t2_k - c * (t2_k - tpwb_k);
+
+    0.7 * tnwb_k + 0.3 * t2_k
+}
+
 /// Calculates Globe Temperature.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
@@ -555,11 +1520,60 @@ pub fn calculate_wbt(t2_k: f64, rh: f64) -> f64 {
 ///
 /// The return value is the globe temperature in Kelvin.
 ///
+/// In calm conditions there is no convective exchange, so the globe temperature equals the
+/// mean radiant temperature.
+///
+/// The globe temperature is the root of the heat balance quartic `x^4 + d x - (mrt^4 + d t2_k) = 0`,
+/// which always lies between `t2_k` and `mrt` for positive temperatures. The closed-form solution
+/// loses precision as the wind approaches calm (below about 1e-9 m/s) and eventually returns NaN,
+/// so when it falls outside that range the root is found by bisection instead. The result is NaN
+/// only if an input is NaN.
+///
+/// This assumes the standard black globe, [`constants::STANDARD_GLOBE_DIAMETER_M`] (0.15 m) across
+/// with [`constants::STANDARD_GLOBE_EMISSIVITY`] (0.95); see [`calculate_bgt_custom`] for other globes.
+///
 /// Reference: Guo et al. 2018 [https://doi.org/10.1016/j.enbuild.2018.08.029](https://doi.org/10.1016/j.enbuild.2018.08.029)
 pub fn calculate_bgt(t2_k: f64, mrt: f64, va: f64) -> f64 {
+    calculate_bgt_custom(
+        t2_k,
+        mrt,
+        va,
+        STANDARD_GLOBE_DIAMETER_M,
+        STANDARD_GLOBE_EMISSIVITY,
+    )
+}
+
+/// Calculates the temperature of a globe of any size and emissivity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `diameter_m` is the globe diameter in meters.
+///
+/// Where `emissivity` is the emissivity of the globe surface.
+///
+/// The return value is the globe temperature in Kelvin.
+///
+/// The convective heat transfer coefficient of the globe scales as `v^0.6 / D^0.4`, so a smaller
+/// globe, such as the 0.05 m grey globes used in microclimate studies, is more strongly coupled to
+/// the air temperature and less to the radiant environment. See [`calculate_bgt`] for the solver.
+///
+/// Reference: Guo et al. 2018 [https://doi.org/10.1016/j.enbuild.2018.08.029](https://doi.org/10.1016/j.enbuild.2018.08.029)
+pub fn calculate_bgt_custom(t2_k: f64, mrt: f64, va: f64, diameter_m: f64, emissivity: f64) -> f64 {
+    if t2_k.is_nan() || mrt.is_nan() || va.is_nan() {
+        return f64::NAN;
+    }
+
     let v = scale_windspeed(va, 1.1); // formula requires wind speed at 1.1m (i.e., at the level of the globe)
 
-    let d = (1.1e8 * v.powf(0.6)) / (0.95 * f64::from(0.15).powf(0.4));
+    let d = (GLOBE_FORCED_CONVECTION_OVER_STEFAN_BOLTZMANN * v.powf(0.6))
+        / (emissivity * diameter_m.powf(0.4));
+    if d == 0.0 {
+        return mrt;
+    }
     let e = -(mrt.powi(4)) - d * t2_k;
 
     let q: f64 = 12.0 * e;
@@ -569,75 +1583,508 @@ pub fn calculate_bgt(t2_k: f64, mrt: f64, va: f64) -> f64 {
 
     let bgt = -q + 0.5 * (-4.0 * (q.powi(2)) + d / q).sqrt();
 
-    return bgt;
+    let (lo, hi) = if t2_k < mrt { (t2_k, mrt) } else { (mrt, t2_k) };
+    if (lo..=hi).contains(&bgt) {
+        bgt
+    } else {
+        let options = numeric::SolverOptions {
+            tolerance: 0.0,
+            max_iterations: 200,
+        };
+        numeric::bisect(|x| x.powi(4) + d * x + e, lo, hi, options).unwrap_or(0.5 * (lo + hi))
+    }
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT).
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// The return value is the wet bulb globe temperature in Kelvin.
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/](http://www.bom.gov.au/info/thermal_stress/)
+pub fn calculate_wbgt(t2_k: f64, mrt: f64, va: f64, td_k: f64) -> f64 {
+    let rh = calculate_relative_humidity_percent(t2_k, td_k);
+    wbgt_from_relative_humidity(t2_k, mrt, va, rh)
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) and its uncertainty from those of its inputs.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// Where `sigma_inputs` is `(sigma_t2, sigma_mrt, sigma_va, sigma_td)`, the standard deviations of
+/// the inputs in K, K, m/s and K. They are taken to be independent.
+///
+/// The return value is `(wbgt, sigma)`: [`calculate_wbgt`] in Kelvin and its first-order propagated
+/// standard deviation in K, `sqrt(sum((dWBGT/dx_i sigma_i)^2))`. The partial derivatives are central
+/// differences with a step of 1e-4 in each input, since the globe temperature is the root of a
+/// quartic and has no convenient closed-form derivative. This linearises WBGT about the inputs, so
+/// it is accurate while WBGT is close to linear over a few standard deviations of each input. It
+/// underestimates the spread when an input is near a kink, as in light winds, where the globe
+/// temperature changes fastest with wind speed, or when the dew point is close to the air
+/// temperature, where humidity is clamped at 100%.
+///
+/// Reference: JCGM 100:2008, Evaluation of measurement data - Guide to the expression of
+/// uncertainty in measurement, section 5.1
+pub fn calculate_wbgt_with_uncertainty(
+    t2_k: f64,
+    mrt: f64,
+    va: f64,
+    td_k: f64,
+    sigma_inputs: (f64, f64, f64, f64),
+) -> (f64, f64) {
+    const STEP: f64 = 1e-4;
+    let wbgt = calculate_wbgt(t2_k, mrt, va, td_k);
+
+    let central = |f: &dyn Fn(f64) -> f64| (f(STEP) - f(-STEP)) / (2.0 * STEP);
+    let d_t2 = central(&|h| calculate_wbgt(t2_k + h, mrt, va, td_k));
+    let d_mrt = central(&|h| calculate_wbgt(t2_k, mrt + h, va, td_k));
+    let d_va = central(&|h| calculate_wbgt(t2_k, mrt, va + h, td_k));
+    let d_td = central(&|h| calculate_wbgt(t2_k, mrt, va, td_k + h));
+    trace_values!(d_t2, d_mrt, d_va, d_td);
+
+    let (sigma_t2, sigma_mrt, sigma_va, sigma_td) = sigma_inputs;
+    let variance = (d_t2 * sigma_t2).powi(2)
+        + (d_mrt * sigma_mrt).powi(2)
+        + (d_va * sigma_va).powi(2)
+        + (d_td * sigma_td).powi(2);
+    (wbgt, variance.sqrt())
+}
+
+/// Selects whether Wet Bulb Globe Temperature is for the sun or the shade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WbgtExposure {
+    /// Exposed to the mean radiant temperature, see [`calculate_wbgt`].
+    Sun,
+    /// Fully shaded, so the globe is at air temperature.
+    Shade,
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) in the sun or in the shade.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin. It is ignored in [`WbgtExposure::Shade`].
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// Where `exposure` selects the sun or shade case.
+///
+/// The return value is the wet bulb globe temperature in Kelvin, `0.7 Tw + 0.2 Tg + 0.1 Ta`.
+/// [`WbgtExposure::Sun`] is [`calculate_wbgt`], with the globe temperature `Tg` from
+/// [`calculate_bgt`]. In [`WbgtExposure::Shade`] there is no radiant load, so `Tg = Ta` and the
+/// weighting collapses to `0.7 Tw + 0.3 Ta`, the indoor form of WBGT; this is [`calculate_wbgt`]
+/// with `mrt` set to `t2_k`. The shade case is lower than the sun case whenever the mean radiant
+/// temperature is above the air temperature.
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/](http://www.bom.gov.au/info/thermal_stress/)
+pub fn calculate_wbgt_exposure(
+    t2_k: f64,
+    mrt: f64,
+    va: f64,
+    td_k: f64,
+    exposure: WbgtExposure,
+) -> f64 {
+    match exposure {
+        WbgtExposure::Sun => calculate_wbgt(t2_k, mrt, va, td_k),
+        // With the mean radiant temperature at air temperature the globe is too.
+        WbgtExposure::Shade => calculate_wbgt(t2_k, t2_k, va, td_k),
+    }
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) from water vapour pressure.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `eh_pa` is the water vapour pressure in hPa.
+///
+/// The return value is the wet bulb globe temperature in Kelvin. The relative humidity is
+/// `eh_pa` divided by the Tetens saturation vapour pressure at `t2_k`, the same formula
+/// [`calculate_relative_humidity_percent`] uses, so this agrees with [`calculate_wbgt`] given the
+/// vapour pressure at the dew point.
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+pub fn calculate_wbgt_from_vapour_pressure(t2_k: f64, mrt: f64, va: f64, eh_pa: f64) -> f64 {
+    let es = tetens_vapour_pressure(kelvin_to_celsius(t2_k));
+    let rh = (eh_pa / es) * 100.0;
+    wbgt_from_relative_humidity(t2_k, mrt, va, rh)
+}
+
+pub(crate) fn wbgt_from_relative_humidity(t2_k: f64, mrt: f64, va: f64, rh: f64) -> f64 {
+    wbgt_components_from_relative_humidity(t2_k, mrt, va, rh).wbgt
+}
+
+fn wbgt_components_from_relative_humidity(t2_k: f64, mrt: f64, va: f64, rh: f64) -> WbgtComponents {
+    trace_span!("calculate_wbgt", t2_k, mrt, va, rh);
+    let bgt_k = calculate_bgt(t2_k, mrt, va);
+    let bgt_c = kelvin_to_celsius(bgt_k);
+
+    let t2_c = kelvin_to_celsius(t2_k);
+    let tw_k = calculate_wbt(t2_k, rh);
+    let tw_c = kelvin_to_celsius(tw_k);
+    trace_values!(bgt_k, tw_k);
+
+    let wbgt = 0.7 * tw_c + 0.2 * bgt_c + 0.1 * t2_c;
+    WbgtComponents {
+        wet_bulb: tw_k,
+        globe: bgt_k,
+        dry_bulb: t2_k,
+        wbgt: celsius_to_kelvin(wbgt),
+    }
+}
+
+/// The temperatures that make up Wet Bulb Globe Temperature, from [`calculate_wbgt_components`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WbgtComponents {
+    /// Wet bulb temperature in Kelvin, weighted 0.7.
+    pub wet_bulb: f64,
+    /// Globe temperature in Kelvin, weighted 0.2.
+    pub globe: f64,
+    /// Dry bulb (air) temperature in Kelvin, weighted 0.1.
+    pub dry_bulb: f64,
+    /// The weighted total, WBGT in Kelvin.
+    pub wbgt: f64,
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) with its component temperatures.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// The return value is a [`WbgtComponents`] whose `wbgt` is [`calculate_wbgt`], the outdoor
+/// weighting `0.7 Tw + 0.2 Tg + 0.1 Ta` of the wet bulb, globe and dry bulb temperatures. The wet
+/// bulb term carries most of the weight, which is why humidity dominates WBGT: a 1 K rise in the wet
+/// bulb raises WBGT by 0.7 K, the globe by 0.2 K and the air by 0.1 K, before allowing for the air
+/// temperature also raising the other two. The wet bulb is the psychrometric wet bulb of
+/// [`calculate_wbt`], standing in for the natural wet bulb of a measured WBGT, and the globe is that
+/// of [`calculate_bgt`].
+///
+/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/](http://www.bom.gov.au/info/thermal_stress/)
+pub fn calculate_wbgt_components(t2_k: f64, mrt: f64, va: f64, td_k: f64) -> WbgtComponents {
+    let rh = calculate_relative_humidity_percent(t2_k, td_k);
+    wbgt_components_from_relative_humidity(t2_k, mrt, va, rh)
+}
+
+/// Calculates Mean Radiant Temperature from Globe Temperature.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `bgt_k` is the globe temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// The return value is the mean radiant temperature in Kelvin.
+///
+/// This assumes the standard black globe, [`constants::STANDARD_GLOBE_DIAMETER_M`] (0.15 m) across
+/// with [`constants::STANDARD_GLOBE_EMISSIVITY`] (0.95); see [`calculate_mrt_from_globe`] for other
+/// globes.
+///
+/// Reference: Brimicombe et al. (2023) [https://doi.org/10.1029/2022GH000701](https://doi.org/10.1029/2022GH000701)
+pub fn calculate_mrt_from_bgt(t2_k: f64, bgt_k: f64, va: f64) -> f64 {
+    calculate_mrt_from_globe(
+        t2_k,
+        bgt_k,
+        va,
+        STANDARD_GLOBE_DIAMETER_M,
+        STANDARD_GLOBE_EMISSIVITY,
+    )
+}
+
+/// Calculates Mean Radiant Temperature from the temperature of a globe of any size and emissivity.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `globe_k` is the globe temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `diameter_m` is the globe diameter in meters.
+///
+/// Where `emissivity` is the emissivity of the globe surface.
+///
+/// The return value is the mean radiant temperature in Kelvin.
+///
+/// Reference: Brimicombe et al. (2023) [https://doi.org/10.1029/2022GH000701](https://doi.org/10.1029/2022GH000701)
+pub fn calculate_mrt_from_globe(
+    t2_k: f64,
+    globe_k: f64,
+    va: f64,
+    diameter_m: f64,
+    emissivity: f64,
+) -> f64 {
+    let v = scale_windspeed(va, 1.1); // formula requires wind speed at 1.1m (i.e., at the level of the globe)
+    let f = (GLOBE_FORCED_CONVECTION_OVER_STEFAN_BOLTZMANN * v.powf(0.6))
+        / (emissivity * diameter_m.powf(0.4));
+    let globe4 = globe_k.powi(4);
+    let mrtc = globe4 + f * (globe_k - t2_k);
+    ((mrtc).sqrt()).sqrt()
+}
+
+/// Calculates Mean Radiant Temperature from globe temperature under natural (free) convection.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `globe_k` is the globe temperature in Kelvin.
+///
+/// Where `diameter_m` is the globe diameter in meters.
+///
+/// Where `emissivity` is the emissivity of the globe surface.
+///
+/// The return value is the mean radiant temperature in Kelvin. [`calculate_mrt_from_globe`] uses the
+/// forced convection coefficient `1.1e8 σ v^0.6 / D^0.4`, about `6.24 v^0.6 / D^0.4`, which falls
+/// to zero in calm air. Heat is then carried by free convection instead, with the coefficient
+/// `1.4 (|Tg - Ta| / D)^0.25` in W m-2 K-1.
+///
+/// Reference: ISO 7726 (1998), Ergonomics of the thermal environment, Annex B
+pub fn calculate_mrt_from_globe_natural(
+    t2_k: f64,
+    globe_k: f64,
+    diameter_m: f64,
+    emissivity: f64,
+) -> f64 {
+    let h = natural_globe_convection(t2_k, globe_k, diameter_m);
+    mrt_from_globe_coefficient(t2_k, globe_k, h, emissivity)
+}
+
+/// Calculates Mean Radiant Temperature from globe temperature, choosing the convection regime.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `globe_k` is the globe temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `diameter_m` is the globe diameter in meters.
+///
+/// Where `emissivity` is the emissivity of the globe surface.
+///
+/// The return value is the mean radiant temperature in Kelvin, using whichever of the forced and
+/// natural convection coefficients is larger, as ISO 7726 recommends. Natural convection takes over
+/// below the wind speed at globe height where the two are equal,
+/// `v = (0.224 D^0.15 |Tg - Ta|^0.25)^(1/0.6)`: about 0.13 m/s for a 0.15 m globe 10 K above the air.
+/// Above it the result equals [`calculate_mrt_from_globe`].
+///
+/// Reference: ISO 7726 (1998), Ergonomics of the thermal environment, Annex B
+pub fn calculate_mrt_from_globe_regime(
+    t2_k: f64,
+    globe_k: f64,
+    va: f64,
+    diameter_m: f64,
+    emissivity: f64,
+) -> f64 {
+    let natural = natural_globe_convection(t2_k, globe_k, diameter_m);
+    let forced = forced_globe_convection(va, diameter_m);
+    if natural > forced {
+        mrt_from_globe_coefficient(t2_k, globe_k, natural, emissivity)
+    } else {
+        calculate_mrt_from_globe(t2_k, globe_k, va, diameter_m, emissivity)
+    }
+}
+
+/// Calculates Mean Radiant Temperature from black globe temperature, choosing the convection regime.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `bgt_k` is the black globe temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// The return value is the mean radiant temperature in Kelvin. This is
+/// [`calculate_mrt_from_globe_regime`] for the standard 0.15 m black globe with emissivity 0.95 of
+/// [`calculate_mrt_from_bgt`], and differs from it only in near calm air.
+///
+/// Reference: ISO 7726 (1998), Ergonomics of the thermal environment, Annex B
+pub fn calculate_mrt_from_bgt_regime(t2_k: f64, bgt_k: f64, va: f64) -> f64 {
+    calculate_mrt_from_globe_regime(
+        t2_k,
+        bgt_k,
+        va,
+        STANDARD_GLOBE_DIAMETER_M,
+        STANDARD_GLOBE_EMISSIVITY,
+    )
+}
+
+/// Forced convection coefficient of a globe in W m-2 K-1, from the 10m wind speed.
+fn forced_globe_convection(va: f64, diameter_m: f64) -> f64 {
+    let v = scale_windspeed(va, 1.1);
+    GLOBE_FORCED_CONVECTION_OVER_STEFAN_BOLTZMANN * STEFAN_BOLTZMANN * v.powf(0.6)
+        / diameter_m.powf(0.4)
+}
+
+/// Natural convection coefficient of a globe in W m-2 K-1.
+fn natural_globe_convection(t2_k: f64, globe_k: f64, diameter_m: f64) -> f64 {
+    1.4 * ((globe_k - t2_k).abs() / diameter_m).powf(0.25)
+}
+
+/// Mean radiant temperature from the globe heat balance with convection coefficient `h`.
+fn mrt_from_globe_coefficient(t2_k: f64, globe_k: f64, h: f64, emissivity: f64) -> f64 {
+    let mrt4 = globe_k.powi(4) + h / (emissivity * STEFAN_BOLTZMANN) * (globe_k - t2_k);
+    mrt4.sqrt().sqrt()
+}
+
+/// Calculates Humidex.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// The return value is Humidex in Kelvin.
+///
+/// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
+pub fn calculate_humidex(t2_k: f64, td_k: f64) -> f64 {
+    let vp = 6.11
+        * (LATENT_HEAT_OVER_VAPOUR_GAS_CONSTANT_K * ((1.0 / TRIPLE_POINT_K) - (1.0 / td_k))).exp(); // vapour pressure [hPa]
+    let h = 0.5555 * (vp - 10.0);
+    trace_values!(vp);
+    t2_k + h
+}
+
+/// Calculates the water vapour pressure implied by a Humidex value.
+///
+/// Where `humidex_k` is the Humidex in Kelvin.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the water vapour pressure in hPa. [`calculate_humidex`] is
+/// `H = T + 0.5555 (e - 10)`, linear in `e`, so this is `e = (H - T) / 0.5555 + 10` exactly. The
+/// result is not checked: it is negative when `humidex_k` is more than 5.555 K below `t2_k`, and
+/// may exceed the saturation vapour pressure at `t2_k`. For the dew point, with those cases ruled
+/// out, use [`solve_dew_point_for_humidex`].
+pub fn solve_vapour_pressure_from_humidex(humidex_k: f64, t2_k: f64) -> f64 {
+    (humidex_k - t2_k) / 0.5555 + 10.0
+}
+
+/// Calculates the dew point temperature at which Humidex reaches a target value.
+///
+/// Where `target_humidex_k` is the target Humidex in Kelvin.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// The return value is the dew point temperature in Kelvin, or `None` if no physical dew point
+/// gives the target. [`calculate_humidex`] is `H = T + 0.5555 (e - 10)` with
+/// `e = 6.11 exp(5417.7530 (1/273.16 - 1/Td))`, which is monotonic in `Td`, so it inverts exactly:
+/// `e = (H - T) / 0.5555 + 10` and `Td = 1 / (1/273.16 - ln(e / 6.11) / 5417.7530)`. The result is
+/// `None` when the target needs a non-positive vapour pressure, or a dew point above `t2_k`, which
+/// would mean supersaturated air.
+pub fn solve_dew_point_for_humidex(target_humidex_k: f64, t2_k: f64) -> Option<f64> {
+    let vp = solve_vapour_pressure_from_humidex(target_humidex_k, t2_k);
+    if vp.is_nan() || vp <= 0.0 {
+        return None;
+    }
+
+    let inv_td = 1.0 / TRIPLE_POINT_K - (vp / 6.11).ln() / LATENT_HEAT_OVER_VAPOUR_GAS_CONSTANT_K;
+    let td_k = 1.0 / inv_td;
+    if inv_td > 0.0 && td_k <= t2_k {
+        Some(td_k)
+    } else {
+        None
+    }
 }
 
-/// Calculates Wet Bulb Globe Temperature (WBGT).
+/// Calculates Humidex from relative humidity.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
 ///
-/// Where `mrt` is the mean radiant temperature in Kelvin.
+/// Where `rh` is the relative humidity percentage.
 ///
-/// Where `va` is the wind speed at 10 meters in m/s.
+/// The return value is Humidex in Kelvin. The dew point is found with
+/// [`calculate_dew_point_from_relative_humidity`] and passed to [`calculate_humidex`], so this is
+/// equivalent to the dew point form up to the small round-trip drift between the two humidity
+/// formulas described on [`calculate_relative_humidity_percent`].
 ///
-/// Where `td_k` is the dew point temperature in Kelvin.
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
 ///
-/// The return value is the wet bulb globe temperature in Kelvin.
+/// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
+pub fn calculate_humidex_from_rh(t2_k: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let td_k = calculate_dew_point_from_relative_humidity(rh, t2_k);
+    calculate_humidex(t2_k, td_k)
+}
+
+/// Calculates Humidex, rejecting non-physical inputs.
 ///
-/// Reference: Stull (2011) [https://doi.org/10.1175/JAMC-D-11-0143.1](https://doi.org/10.1175/JAMC-D-11-0143.1)
+/// Where `t2_k` is the 2m temperature in Kelvin.
 ///
-/// See also: [http://www.bom.gov.au/info/thermal_stress/](http://www.bom.gov.au/info/thermal_stress/)
-pub fn calculate_wbgt(t2_k: f64, mrt: f64, va: f64, td_k: f64) -> f64 {
-    let bgt_k = calculate_bgt(t2_k, mrt, va);
-    let bgt_c = kelvin_to_celsius(bgt_k);
-
-    let rh = calculate_relative_humidity_percent(t2_k, td_k);
-    let t2_c = kelvin_to_celsius(t2_k);
-    let tw_k = calculate_wbt(t2_k, rh);
-    let tw_c = kelvin_to_celsius(tw_k);
-
-    let wbgt = 0.7 * tw_c + 0.2 * bgt_c + 0.1 * t2_c;
-    let wbgt_k = celsius_to_kelvin(wbgt);
-
-    return wbgt_k;
+/// Where `td_k` is the dew point temperature in Kelvin.
+///
+/// The return value is Humidex in Kelvin, as from [`calculate_humidex`], or `None` if either input
+/// is not finite or `td_k` is not positive. The vapour pressure term `exp(5417.7530 (1/273.16 - 1/td_k))`
+/// blows up as `td_k` approaches zero, so bad data would otherwise produce infinities or garbage.
+/// Humidex is only meaningful for realistic dew points, roughly -50°C to 35°C, and is normally
+/// reported only when the air temperature is at least 20°C.
+///
+/// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
+pub fn calculate_humidex_checked(t2_k: f64, td_k: f64) -> Option<f64> {
+    if !t2_k.is_finite() || !td_k.is_finite() || td_k <= 0.0 {
+        return None;
+    }
+    Some(calculate_humidex(t2_k, td_k))
 }
 
-/// Calculates Mean Radiant Temperature from Globe Temperature.
+/// Calculates the Temperature-Humidity Index (THI) for cattle.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
 ///
-/// Where `bgt_k` is the globe temperature in Kelvin.
-///
-/// Where `va` is the wind speed at 10 meters in m/s.
+/// Where `rh` is the relative humidity percentage.
 ///
-/// The return value is the mean radiant temperature in Kelvin.
+/// The return value is the THI (dimensionless), `0.8 T + RH/100 (T - 14.4) + 46.4` with `T` in
+/// Celsius. This is a livestock index, on a different scale from the human indices: it is
+/// calibrated against the heat stress of beef and dairy cattle, which begins at a THI of about 75
+/// (see [`classify_cattle_thi`]), and should not be used for people or other species.
+/// It is numerically within about 0.2 of the NRC (1971) form used on US livestock charts.
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
 ///
-/// Reference: Brimicombe et al. (2023) [https://doi.org/10.1029/2022GH000701](https://doi.org/10.1029/2022GH000701)
-pub fn calculate_mrt_from_bgt(t2_k: f64, bgt_k: f64, va: f64) -> f64 {
-    let v = scale_windspeed(va, 1.1); // formula requires wind speed at 1.1m (i.e., at the level of the globe)
-    let f = (1.1e8 * v.powf(0.6)) / (0.95 * f64::from(0.15).powf(0.4));
-    let bgt4 = bgt_k.powi(4);
-    let mrtc = bgt4 + f * (bgt_k - t2_k);
-    let mrtc2 = ((mrtc).sqrt()).sqrt();
-
-    return mrtc2;
+/// Reference: Mader, Davis and Brown-Brandl (2006) [https://doi.org/10.2527/2006.843712x](https://doi.org/10.2527/2006.843712x)
+pub fn calculate_cattle_thi(t2_k: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let t2_c = kelvin_to_celsius(t2_k);
+    0.8 * t2_c + rh / 100.0 * (t2_c - 14.4) + 46.4
 }
 
-/// Calculates Humidex.
+/// Calculates Humiture.
 ///
 /// Where `t2_k` is the 2m temperature in Kelvin.
 ///
 /// Where `td_k` is the dew point temperature in Kelvin.
 ///
-/// The return value is Humidex in Kelvin.
+/// The return value is humiture in Kelvin, `T + 5/9 (e - 10)` with `T` in Celsius and `e` the
+/// vapour pressure at the dew point in hPa. This has the same form as [`calculate_humidex`], but the
+/// vapour pressure is from the Tetens formula used by [`calculate_relative_humidity_percent`] rather
+/// than the Clausius-Clapeyron form of Humidex, which gives about 0.1°C less in warm, humid air.
 ///
-/// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
-pub fn calculate_humidex(t2_k: f64, td_k: f64) -> f64 {
-    let vp = 6.11 * f64::from(5417.7530 * ((1.0 / 273.16) - (1.0 / td_k))).exp(); // vapour pressure [hPa]
-    let h = 0.5555 * (vp - 10.0);
-    let humidex = t2_k + h;
-
-    return humidex;
+/// Reference: Winterling (1979), Humiture - revised and adapted for the summer season in
+/// Jacksonville, Fla., Bulletin of the American Meteorological Society 60(4)
+pub fn calculate_humiture(t2_k: f64, td_k: f64) -> f64 {
+    let e = tetens_vapour_pressure(kelvin_to_celsius(td_k));
+    t2_k + 5.0 / 9.0 * (e - 10.0)
 }
 
 /// Calculates Normal Effective Temperature (NET).
@@ -650,16 +2097,21 @@ pub fn calculate_humidex(t2_k: f64, td_k: f64) -> f64 {
 ///
 /// The return value is the normal effective temperature in Kelvin.
 ///
+/// The wind speed is scaled to 1.2m by [`scale_windspeed`], which treats negative wind speeds from
+/// bad data as calm. Without this `v^0.75` would be NaN; the denominator `1.76 + 1.4 v^0.75` is at
+/// least 1.76 for any non-negative wind, so it never divides by zero. NaN wind still gives NaN.
+///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
 /// Reference: Li and Chan (2006) [https://doi.org/10.1017/S1350482700001602](https://doi.org/10.1017/S1350482700001602)
 pub fn calculate_normal_effective_temperature(t2_k: f64, va: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
     let t2_k = kelvin_to_celsius(t2_k);
     let v = scale_windspeed(va, 1.2); // formula requires wind speed at 1.2m
     let ditermeq = 1.0 / (1.76 + 1.4 * v.powf(0.75));
     let net =
         37.0 - ((37.0 - t2_k) / (0.68 - 0.0014 * rh + ditermeq)) - 0.29 * t2_k * (1.0 - 0.01 * rh);
-    let net_k = celsius_to_kelvin(net);
-
-    return net_k;
+    celsius_to_kelvin(net)
 }
 
 /// Calculates Apparent Temperature
@@ -672,17 +2124,165 @@ pub fn calculate_normal_effective_temperature(t2_k: f64, va: f64, rh: f64) -> f6
 ///
 /// The return value is the apparent temperature in Kelvin.
 ///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`]. Negative wind
+/// speeds are treated as calm (0 m/s).
+///
 /// Reference: Steadman (1984) [https://doi.org/10.1175/1520-0450(1984)023%3C1674:AUSOAT%3E2.0.CO;2](https://doi.org/10.1175/1520-0450(1984)023%3C1674:AUSOAT%3E2.0.CO;2)
 ///
 /// See also: [http://www.bom.gov.au/info/thermal_stress/#atapproximation](http://www.bom.gov.au/info/thermal_stress/#atapproximation)
 pub fn calculate_apparent_temperature(t2_k: f64, va: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let va = calm_if_negative(va);
     let t2_c = kelvin_to_celsius(t2_k);
+    trace_span!("calculate_apparent_temperature", t2_k, va, rh);
     let e = calculate_nonsaturation_vapour_pressure(t2_k, rh);
-    println!("{t2_k} {e} {rh}");
+    trace_values!(e);
     let at = t2_c + 0.33 * e - 0.7 * va - 4.0;
-    let at_k = celsius_to_kelvin(at);
+    celsius_to_kelvin(at)
+}
+
+/// Calculates Apparent Temperature with the original Steadman (1979) model.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is the apparent temperature in Kelvin, `-1.3 + 0.92 T + 2.2 e` with `T` in
+/// Celsius and `e` the vapour pressure in kPa from [`calculate_nonsaturation_vapour_pressure`].
+///
+/// Steadman (1979) tabulated the sultriness of still, shaded air for an indoor-clothed adult; this
+/// is the linear fit to those tables used in heat and health studies, and the NWS heat index is a
+/// polynomial fit to the same tables. It has no wind term. Steadman (1984) extended the model
+/// outdoors with wind and radiation, and [`calculate_apparent_temperature`] uses the Bureau of
+/// Meteorology approximation descended from that work, which subtracts 4°C and 0.7°C per m/s of
+/// wind, so the two disagree even in calm air. The linear fit tracks the tables best between about
+/// 20°C and 35°C and underestimates them in hot, humid air. Relative humidity outside 0% to 100% is
+/// clamped by [`normalize_relative_humidity`].
+///
+/// Reference: Steadman (1979) [https://doi.org/10.1175/1520-0450(1979)018%3C0861:TAOSPI%3E2.0.CO;2](https://doi.org/10.1175/1520-0450(1979)018%3C0861:TAOSPI%3E2.0.CO;2)
+pub fn calculate_apparent_temperature_steadman1979(t2_k: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let t2_c = kelvin_to_celsius(t2_k);
+    let e_kpa = calculate_nonsaturation_vapour_pressure(t2_k, rh) / 10.0;
+    celsius_to_kelvin(-1.3 + 0.92 * t2_c + 2.2 * e_kpa)
+}
+
+/// Calculates Apparent Temperature including the effect of solar radiation.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `q` is the net radiation absorbed per unit area of body surface in W/m^2.
+///
+/// The return value is the apparent temperature in Kelvin.
+///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`]. Negative wind
+/// speeds are treated as calm (0 m/s).
+///
+/// Reference: Steadman (1994) [https://doi.org/10.1016/0004-6981(94)90300-X](https://doi.org/10.1016/0004-6981(94)90300-X)
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/#atapproximation](http://www.bom.gov.au/info/thermal_stress/#atapproximation)
+pub fn calculate_apparent_temperature_with_radiation(t2_k: f64, va: f64, rh: f64, q: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let va = calm_if_negative(va);
+    let t2_c = kelvin_to_celsius(t2_k);
+    let e = calculate_nonsaturation_vapour_pressure(t2_k, rh);
+    let at = t2_c + 0.348 * e - 0.7 * va + 0.7 * q / (va + 10.0) - 4.25;
+    celsius_to_kelvin(at)
+}
+
+/// Calculates net radiation absorbed by a person, for use as `q` in
+/// [`calculate_apparent_temperature_with_radiation`].
+///
+/// Where `ssrd` is the surface solar radiation downwards in W m-2.
+///
+/// Where `ssr` is the surface net solar radiation in W m-2.
+///
+/// Where `dsrp` is the direct solar radiation in W m-2.
+///
+/// Where `strd` is the surface thermal radiation downwards in W m-2.
+///
+/// Where `fdir` is the total sky direct solar radiation at surface in W m-2.
+///
+/// Where `strr` is the surface net thermal radiation in W m-2.
+///
+/// Where `cossza` is the cosine of the solar zenith angle (dimensionless).
+///
+/// Where `albedo` is the shortwave albedo of the body surface (dimensionless), typically around 0.3
+/// for clothed skin.
+///
+/// The return value is the net absorbed radiation in W m-2 of body surface. The body has the
+/// geometry of [`calculate_mean_radiant_temperature`]: half its surface sees the sky and half the
+/// ground, in both diffuse and reflected shortwave and in longwave, and the direct beam falls on
+/// the projected area given by [`calculate_projected_area_factor`]. It absorbs `1 - albedo` of the
+/// shortwave and, with the longwave emissivity of 0.97, emits thermal radiation like the ground
+/// beneath it. With an albedo of 0.3 the absorbed part is exactly the `0.97 sigma MRT^4` of the mean
+/// radiant temperature, and the emitted part leaves `0.485 strr` of longwave. This is negative at
+/// night, when the body loses more thermal radiation than it gains.
+///
+/// Reference: Di Napoli et al. (2020) [https://link.springer.com/article/10.1007/s00484-020-01900-5](https://link.springer.com/article/10.1007/s00484-020-01900-5)
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_net_radiation_absorbed(
+    ssrd: f64,
+    ssr: f64,
+    dsrp: f64,
+    strd: f64,
+    fdir: f64,
+    strr: f64,
+    cossza: f64,
+    albedo: f64,
+) -> f64 {
+    let dsw = ssrd - fdir;
+    let rsw = ssrd - ssr;
+    let lur = strd - strr;
+    let fp = calculate_projected_area_factor(cossza);
+
+    let shortwave = (1.0 - albedo) * (0.5 * dsw + 0.5 * rsw + fp * dsrp);
+    let longwave = 0.97 * (0.5 * strd + 0.5 * lur) - 0.97 * lur;
+    shortwave + longwave
+}
+
+/// Selects which Bureau of Meteorology apparent temperature formula to use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApparentTemperatureMode {
+    /// Apparent temperature in the shade, see [`calculate_apparent_temperature`].
+    Shade,
+    /// Apparent temperature in the sun, see [`calculate_apparent_temperature_with_radiation`].
+    Sun,
+}
 
-    return at_k;
+/// Calculates Apparent Temperature in the shade or in the sun.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `q` is the net radiation absorbed per unit area of body surface in W/m^2. It is ignored in
+/// [`ApparentTemperatureMode::Shade`].
+///
+/// Where `mode` selects the formula.
+///
+/// The return value is the apparent temperature in Kelvin.
+///
+/// See also: [http://www.bom.gov.au/info/thermal_stress/#atapproximation](http://www.bom.gov.au/info/thermal_stress/#atapproximation)
+pub fn calculate_apparent_temperature_mode(
+    t2_k: f64,
+    va: f64,
+    rh: f64,
+    q: f64,
+    mode: ApparentTemperatureMode,
+) -> f64 {
+    match mode {
+        ApparentTemperatureMode::Shade => calculate_apparent_temperature(t2_k, va, rh),
+        ApparentTemperatureMode::Sun => {
+            calculate_apparent_temperature_with_radiation(t2_k, va, rh, q)
+        }
+    }
 }
 
 /// Calculates Wind Chill.
@@ -695,17 +2295,85 @@ pub fn calculate_apparent_temperature(t2_k: f64, va: f64, rh: f64) -> f64 {
 ///
 /// Computation is only valid for temperatures between -50°C and 5°C and wind speeds between 5km/h and 80km/h.
 /// For input values outside those ranges, computed results should not be considered valid.
+/// Negative wind speeds are treated as calm (0 m/s).
 ///
 /// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
 ///
 /// See also: [https://web.archive.org/web/20130627223738/http://climate.weatheroffice.gc.ca/prods_servs/normals_documentation_e.html](https://web.archive.org/web/20130627223738/http://climate.weatheroffice.gc.ca/prods_servs/normals_documentation_e.html)
 pub fn calculate_wind_chill(t2_k: f64, va: f64) -> f64 {
     let t2_c = kelvin_to_celsius(t2_k);
-    let v = va * 3.6; // convert to kilometers per hour
+    let v = calm_if_negative(va) * 3.6; // convert to kilometers per hour
     let windchill = 13.12 + 0.6215 * t2_c - 11.37 * v.powf(0.16) + 0.3965 * t2_c * v.powf(0.16);
-    let windchill_k = celsius_to_kelvin(windchill);
+    celsius_to_kelvin(windchill)
+}
+
+/// Calculates Wind Chill as printed in the US National Weather Service wind chill chart.
+///
+/// Where `t2_f` is the 2m temperature in Fahrenheit.
+///
+/// Where `va_mph` is the wind speed at 10 meters (33 feet) in mph.
+///
+/// The return value is the wind chill in Fahrenheit, rounded to the nearest degree as in the chart:
+/// `35.74 + 0.6215 T - 35.75 V^0.16 + 0.4275 T V^0.16`. This is the same 2001 model as
+/// [`calculate_wind_chill`], which uses the metric coefficients of Environment Canada, and both take
+/// the 10 meter wind. Converting its unrounded Kelvin result to Fahrenheit can still land on the
+/// other side of a half degree, so use this function when matching the published chart. Wind chill
+/// is only defined at or below 50°F with wind above 3 mph; elsewhere the air temperature is
+/// returned unrounded, as the NWS does.
+///
+/// Reference: [https://www.weather.gov/safety/cold-wind-chill-chart](https://www.weather.gov/safety/cold-wind-chill-chart)
+pub fn calculate_wind_chill_nws_table(t2_f: f64, va_mph: f64) -> f64 {
+    if t2_f > 50.0 || va_mph <= 3.0 {
+        return t2_f;
+    }
+    let v = va_mph.powf(0.16);
+    (35.74 + 0.6215 * t2_f - 35.75 * v + 0.4275 * t2_f * v).round()
+}
+
+/// Calculates Wind Chill using the pre-2001 Siple-Passel formula.
+///
+/// Where `t2_k` is the 2m Temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// The return value is the wind chill equivalent temperature in Kelvin.
+///
+/// This is the wind chill index used before 2001, kept for historical comparisons; [`calculate_wind_chill`]
+/// should be preferred otherwise. The Siple-Passel wind chill index `(10.45 + 10√v - v)(33 - T)` in
+/// kcal m-2 h-1 is converted to the temperature giving the same index at 1.8 m/s, a brisk walk.
+/// Results are only meaningful for wind speeds above 1.8 m/s (4 mph), below which the formula gives values warmer than the air.
+/// Negative wind speeds are treated as calm (0 m/s).
+///
+/// Reference: Siple and Passel (1945) [https://doi.org/10.2307/985230](https://doi.org/10.2307/985230)
+pub fn calculate_wind_chill_siple(t2_k: f64, va: f64) -> f64 {
+    let wci = siple_wind_chill_index(t2_k, va);
+    let windchill = 33.0 - wci / 22.034;
+    celsius_to_kelvin(windchill)
+}
+
+/// The Siple-Passel wind chill index in kcal m-2 h-1, treating negative wind speeds as calm.
+fn siple_wind_chill_index(t2_k: f64, va: f64) -> f64 {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let v = calm_if_negative(va);
+    (10.45 + 10.0 * v.sqrt() - v) * (33.0 - t2_c)
+}
 
-    return windchill_k;
+/// Calculates the wind chill heat loss rate.
+///
+/// Where `t2_k` is the 2m Temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// The return value is the rate of heat loss from exposed skin at 33°C in W m-2: the Siple-Passel
+/// wind chill index `(10.45 + 10√v - v)(33 - T)` converted from kcal m-2 h-1 by 1.163. This is the
+/// heat-loss form of [`calculate_wind_chill_siple`], which maps the same index to an equivalent
+/// temperature, so one can be recovered from the other. [`calculate_wind_chill`] uses a different
+/// model and has no matching heat-loss form. Values above about 1630 W m-2 (1400 kcal m-2 h-1) mean
+/// exposed flesh freezes within a minute. Negative wind speeds are treated as calm (0 m/s).
+///
+/// Reference: Siple and Passel (1945) [https://doi.org/10.2307/985230](https://doi.org/10.2307/985230)
+pub fn calculate_wind_chill_heat_loss(t2_k: f64, va: f64) -> f64 {
+    siple_wind_chill_index(t2_k, va) * 1.163
 }
 
 /// Calculates Heat Index using a simplified method.
@@ -716,8 +2384,11 @@ pub fn calculate_wind_chill(t2_k: f64, va: f64) -> f64 {
 ///
 /// The return value is the heat index in Kelvin, or `None` if the temperature is too low.
 ///
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
 /// Reference: Blazejczyk et al. (2012) [https://doi.org/10.1007/s00484-011-0453-2](https://doi.org/10.1007/s00484-011-0453-2)
 pub fn calculate_heat_index_simplified(t2_k: f64, rh: f64) -> Option<f64> {
+    let rh = normalize_relative_humidity(rh);
     let t2_c = kelvin_to_celsius(t2_k);
 
     let hiarray = [
@@ -746,7 +2417,7 @@ pub fn calculate_heat_index_simplified(t2_k: f64, rh: f64) -> Option<f64> {
 
     let hi_k = celsius_to_kelvin(hi);
 
-    return Some(hi_k);
+    Some(hi_k)
 }
 
 /// Calculates Heat Index with adjustments.
@@ -757,18 +2428,51 @@ pub fn calculate_heat_index_simplified(t2_k: f64, rh: f64) -> Option<f64> {
 ///
 /// The return value is the heat index in Kelvin, or `None` if conditions are not met for calculation.
 ///
+/// The low humidity adjustment is NOAA's `(13 - RH) / 4 * sqrt((17 - |T - 95|) / 17)` in
+/// Fahrenheit. Earlier versions took the square root of `17 - |T - 95| / 17`, subtracting up to
+/// about 13°F instead of about 3°F, so results below 13% relative humidity are higher than they
+/// were: 81.6°F rather than 80.0°F at 85°F and 11%.
+///
 /// Reference: [https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml](https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml)
 pub fn calculate_heat_index_adjusted(t2_k: f64, td_k: f64) -> Option<f64> {
     let rh = calculate_relative_humidity_percent(t2_k, td_k);
     let t2_f = kelvin_to_fahrenheit(t2_k);
 
+    let simple = steadman_heat_index_f(t2_f, rh);
+    let low_humidity = t2_f < 112.0 && rh <= 13.0;
+    let high_humidity = t2_f < 87.0 && rh > 85.0;
+
+    let hi = if t2_f > 80.0 && (low_humidity || high_humidity) {
+        rothfusz_heat_index_f(t2_f, rh)
+    } else if t2_f < 80.0 || (simple + t2_f / 2.0) < 80.0 {
+        simple
+    } else {
+        return None;
+    };
+
+    let hi_k = fahrenheit_to_kelvin(hi);
+
+    Some(hi_k)
+}
+
+/// The Steadman simple heat index in Fahrenheit, used by NOAA below 80°F.
+fn steadman_heat_index_f(t2_f: f64, rh: f64) -> f64 {
+    0.5 * (t2_f + 61.0 + ((t2_f - 68.0) * 1.2) + (rh * 0.094))
+}
+
+/// The Rothfusz regression in Fahrenheit with the NOAA low and high humidity adjustments, shared by
+/// every heat index above 80°F.
+///
+/// NOAA applies the adjustments above 80°F. Both fall to zero at the edges of their ranges, the low
+/// humidity one at 78°F and the high humidity one at 87°F, so they are applied here from 78°F to
+/// keep the result continuous in `t2_f` and `rh` for [`calculate_heat_index_blended`]; above 80°F
+/// this is exactly the NOAA form.
+fn rothfusz_heat_index_f(t2_f: f64, rh: f64) -> f64 {
     let hiarray = [
         42.379, 2.04901523, 10.1433312, 0.22475541, 0.00683783, 0.05481717, 0.00122874, 0.00085282,
         0.00000199,
     ];
 
-    let hi_initial = 0.5 * (t2_f + 61.0 + ((t2_f - 68.0) * 1.2) + (rh * 0.094));
-
     let mut hi = -hiarray[0] + hiarray[1] * t2_f + hiarray[2] * rh
         - hiarray[3] * t2_f * rh
         - hiarray[4] * t2_f.powi(2)
@@ -777,22 +2481,254 @@ pub fn calculate_heat_index_adjusted(t2_k: f64, td_k: f64) -> Option<f64> {
         + hiarray[7] * t2_f * rh.powi(2)
         - hiarray[8] * t2_f.powi(2) * rh.powi(2);
 
-    if t2_f > 80.0 && t2_f < 112.0 && rh <= 13.0 {
-        let adj = (13.0 - rh) / 4.0 * (17.0 - (t2_f - 95.0).abs() / 17.0).sqrt();
-        hi = hi - adj;
-    } else if t2_f > 80.0 && t2_f < 87.0 && rh > 85.0 {
-        let adj = (rh - 85.0) / 10.0 * ((87.0 - t2_f) / 5.0);
-        hi = hi + adj;
-    } else if t2_f < 80.0 {
-        let adj = 0.5 * (t2_f + 61.0 + ((t2_f - 68.0) * 1.2) + (rh * 0.094));
-        hi = adj;
-    } else if (hi_initial + t2_f / 2.0) < 80.0 {
-        hi = 0.5 * (t2_f + 61.0 + ((t2_f - 68.0) * 1.2) + (rh * 0.094));
+    if t2_f > 78.0 && t2_f < 112.0 && rh <= 13.0 {
+        hi -= (13.0 - rh) / 4.0 * ((17.0 - (t2_f - 95.0).abs()) / 17.0).sqrt();
+    } else if t2_f < 87.0 && rh > 85.0 {
+        hi += (rh - 85.0) / 10.0 * ((87.0 - t2_f) / 5.0);
+    }
+    hi
+}
+
+/// Calculates Heat Index, blending smoothly between the simple and regression forms.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dewpoint temperature in Kelvin.
+///
+/// The return value is the heat index in Kelvin. Below 78°F this is the Steadman simple form and
+/// above 82°F the Rothfusz regression with the NOAA humidity adjustments, the same regression
+/// [`calculate_heat_index_adjusted`] and [`calculate_apparent_temperature_noaa`] use, although
+/// [`calculate_heat_index_adjusted`] returns `None` where no adjustment applies. Between the two
+/// the forms are linearly interpolated by temperature, removing the jump of up to a few degrees
+/// Fahrenheit at 80°F, so the output is continuous and defined for every input.
+///
+/// Reference: [https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml](https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml)
+pub fn calculate_heat_index_blended(t2_k: f64, td_k: f64) -> f64 {
+    let rh = calculate_relative_humidity_percent(t2_k, td_k);
+    let t2_f = kelvin_to_fahrenheit(t2_k);
+
+    let (low, high) = (78.0, 82.0);
+    let hi = if t2_f <= low {
+        steadman_heat_index_f(t2_f, rh)
+    } else if t2_f >= high {
+        rothfusz_heat_index_f(t2_f, rh)
     } else {
-        return None;
+        let w = (t2_f - low) / (high - low);
+        (1.0 - w) * steadman_heat_index_f(t2_f, rh) + w * rothfusz_heat_index_f(t2_f, rh)
+    };
+    fahrenheit_to_kelvin(hi)
+}
+
+/// Calculates a single "feels like" temperature, choosing an index to suit the conditions.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is the feels like temperature in Kelvin:
+///
+/// - [`calculate_wind_chill`] at or below 10°C when the wind is above 4.8 km/h
+/// - [`calculate_heat_index_simplified`] above 27°C
+/// - the air temperature `t2_k` otherwise
+///
+/// These are the thresholds commonly used by weather services to display one number.
+///
+/// See also: [https://www.weather.gov/safety/cold-wind-chill-chart](https://www.weather.gov/safety/cold-wind-chill-chart)
+pub fn calculate_feels_like(t2_k: f64, va: f64, rh: f64) -> f64 {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let va_kmh = va * 3.6;
+
+    if t2_c <= 10.0 && va_kmh > 4.8 {
+        calculate_wind_chill(t2_k, va)
+    } else if t2_c > 27.0 {
+        calculate_heat_index_simplified(t2_k, rh).unwrap_or(t2_k)
+    } else {
+        t2_k
     }
+}
 
-    let hi_k = fahrenheit_to_kelvin(hi);
+/// Calculates the US National Weather Service "feels like" temperature.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value is the apparent temperature in Kelvin:
+///
+/// - the NWS heat index above 80°F (26.7°C): the Steadman simple form, replaced by the Rothfusz
+///   regression with its humidity adjustments once the average of the simple form and the air
+///   temperature reaches 80°F
+/// - [`calculate_wind_chill`], the 2001 NWS formula, at or below 50°F (10°C) when the wind is above
+///   3 mph (1.34 m/s)
+/// - the air temperature `t2_k` otherwise
+///
+/// This is the definition US users see in forecasts. It differs from the Australian
+/// [`calculate_apparent_temperature`], which blends humidity and wind into every value, and from
+/// [`calculate_feels_like`], which uses metric thresholds and a regression fitted in Celsius.
+/// Relative humidity outside 0% to 100% is clamped by [`normalize_relative_humidity`].
+///
+/// Reference: [https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml](https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml)
+///
+/// See also: [https://www.weather.gov/safety/cold-wind-chill-chart](https://www.weather.gov/safety/cold-wind-chill-chart)
+pub fn calculate_apparent_temperature_noaa(t2_k: f64, va: f64, rh: f64) -> f64 {
+    let rh = normalize_relative_humidity(rh);
+    let t2_f = kelvin_to_fahrenheit(t2_k);
+
+    if t2_f > 80.0 {
+        let simple = steadman_heat_index_f(t2_f, rh);
+        let hi = if (simple + t2_f) / 2.0 >= 80.0 {
+            rothfusz_heat_index_f(t2_f, rh)
+        } else {
+            simple
+        };
+        fahrenheit_to_kelvin(hi)
+    } else if t2_f <= 50.0 && va > 3.0 * 0.44704 {
+        calculate_wind_chill(t2_k, va)
+    } else {
+        t2_k
+    }
+}
+
+/// Every thermal index computed from a single set of meteorological inputs.
+///
+/// All temperatures are in Kelvin. Indices that do not apply to the inputs are `None`.
+///
+/// With the `serde` feature this implements `Serialize` and `Deserialize`, using the field names
+/// below as keys and `null` for indices that do not apply.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllIndices {
+    /// Universal Thermal Climate Index in Kelvin, from `t2_k`, `va`, `mrt` and `td_k`.
+    pub utci: f64,
+    /// Wet Bulb Globe Temperature in Kelvin, from `t2_k`, `mrt`, `va` and `td_k`.
+    pub wbgt: f64,
+    /// Humidex in Kelvin, from `t2_k` and `td_k`.
+    pub humidex: f64,
+    /// Apparent temperature in Kelvin, from `t2_k`, `va` and the relative humidity.
+    pub apparent_temperature: f64,
+    /// Simplified heat index in Kelvin, from `t2_k` and the relative humidity, or `None` at or
+    /// below 20°C.
+    pub heat_index: Option<f64>,
+    /// Wind chill in Kelvin, from `t2_k` and `va`, or `None` outside -50°C to 5°C and 5km/h to
+    /// 80km/h.
+    pub wind_chill: Option<f64>,
+    /// Wet bulb temperature in Kelvin, from `t2_k` and the relative humidity.
+    pub wbt: f64,
+    /// Normal effective temperature in Kelvin, from `t2_k`, `va` and the relative humidity.
+    pub net: f64,
+}
+
+impl AllIndices {
+    /// Returns these indices with every value rounded by [`round_to`] to `decimals` places.
+    ///
+    /// Values stay in Kelvin. Since 273.15 has two decimals, rounding to two or more places in
+    /// Kelvin gives the same digits as rounding in Celsius, but coarser rounding does not, so
+    /// convert first if the rounded values are for display in Celsius.
+    pub fn rounded(self, decimals: u32) -> Self {
+        let round = |value| round_to(value, decimals);
+        Self {
+            utci: round(self.utci),
+            wbgt: round(self.wbgt),
+            humidex: round(self.humidex),
+            apparent_temperature: round(self.apparent_temperature),
+            heat_index: self.heat_index.map(round),
+            wind_chill: self.wind_chill.map(round),
+            wbt: round(self.wbt),
+            net: round(self.net),
+        }
+    }
+}
+
+/// Calculates every thermal index from a single set of meteorological inputs.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `td_k` is the 2m dew point temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `mrt` is the mean radiant temperature in Kelvin.
+///
+/// The return value is an [`AllIndices`] holding each index in Kelvin. Indices that need relative
+/// humidity use [`calculate_relative_humidity_percent`] of `t2_k` and `td_k`.
+pub fn calculate_all(t2_k: f64, td_k: f64, va: f64, mrt: f64) -> AllIndices {
+    let rh = calculate_relative_humidity_percent(t2_k, td_k);
+
+    let wind_chill = wind_chill_if_valid(t2_k, va);
+
+    AllIndices {
+        utci: calculate_utci(t2_k, va, mrt, Some(td_k), None),
+        wbgt: calculate_wbgt(t2_k, mrt, va, td_k),
+        humidex: calculate_humidex(t2_k, td_k),
+        apparent_temperature: calculate_apparent_temperature(t2_k, va, rh),
+        heat_index: calculate_heat_index_simplified(t2_k, rh),
+        wind_chill,
+        wbt: calculate_wbt(t2_k, rh),
+        net: calculate_normal_effective_temperature(t2_k, va, rh),
+    }
+}
+
+/// [`calculate_wind_chill`], or `None` outside -50°C to 5°C and 5km/h to 80km/h.
+pub(crate) fn wind_chill_if_valid(t2_k: f64, va: f64) -> Option<f64> {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let va_kmh = va * 3.6;
+    if (-50.0..=5.0).contains(&t2_c) && (5.0..=80.0).contains(&va_kmh) {
+        Some(calculate_wind_chill(t2_k, va))
+    } else {
+        None
+    }
+}
 
-    return Some(hi_k);
+/// Lists the indices whose validity ranges the inputs satisfy.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// The return value holds the names of the applicable [`AllIndices`] fields, in field order. Each
+/// index is included when:
+///
+/// - `"utci"`: the air temperature is from -50°C to 50°C, the wind speed from 0.5 m/s to 17 m/s and
+///   the water vapour pressure at most 5 kPa, as [`calculate_utci_checked`]; the mean radiant
+///   temperature is not an input here, so its range is not checked
+/// - `"wbgt"`, `"apparent_temperature"` and `"net"`: always, as they have no stated range
+/// - `"humidex"`: the air temperature is at least 20°C, below which Humidex is not reported
+/// - `"heat_index"`: the air temperature is above 20°C, as [`calculate_heat_index_simplified`]
+/// - `"wind_chill"`: the air temperature is from -50°C to 5°C and the wind speed from 5 km/h to
+///   80 km/h, as [`calculate_all`]
+/// - `"wbt"`: the air temperature is from -20°C to 50°C and the relative humidity from 5% to 99%,
+///   the range of the Stull (2011) fit used by [`calculate_wbt`]
+pub fn applicable_indices(t2_k: f64, va: f64, rh: f64) -> Vec<&'static str> {
+    let t2_c = kelvin_to_celsius(t2_k);
+    let va_kmh = va * 3.6;
+    let wvp = relative_humidity_to_vapour_pressure(t2_k, normalize_relative_humidity(rh)) / 10.0;
+
+    [
+        (
+            "utci",
+            (-50.0..=50.0).contains(&t2_c) && (0.5..=17.0).contains(&va) && wvp <= 5.0,
+        ),
+        ("wbgt", true),
+        ("humidex", t2_c >= 20.0),
+        ("apparent_temperature", true),
+        ("heat_index", t2_c > 20.0),
+        (
+            "wind_chill",
+            (-50.0..=5.0).contains(&t2_c) && (5.0..=80.0).contains(&va_kmh),
+        ),
+        (
+            "wbt",
+            (-20.0..=50.0).contains(&t2_c) && (5.0..=99.0).contains(&rh),
+        ),
+        ("net", true),
+    ]
+    .into_iter()
+    .filter_map(|(name, applicable)| applicable.then_some(name))
+    .collect()
 }
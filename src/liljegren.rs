@@ -0,0 +1,274 @@
+use std::f64::consts::PI;
+
+use crate::constants::STEFAN_BOLTZMANN;
+use crate::numeric::{self, SolverOptions};
+use crate::scale_windspeed;
+
+const CP: f64 = 1003.5; // specific heat capacity of air at constant pressure [J kg-1 K-1]
+const M_AIR: f64 = 28.97; // molecular weight of dry air [kg kmol-1]
+const M_H2O: f64 = 18.015; // molecular weight of water vapour [kg kmol-1]
+const R_AIR: f64 = 8314.34 / M_AIR; // gas constant for dry air [J kg-1 K-1]
+const RATIO: f64 = CP * M_AIR / M_H2O;
+const PR: f64 = CP / (CP + 1.25 * R_AIR); // Prandtl number
+
+const EMIS_WICK: f64 = 0.95;
+const ALB_WICK: f64 = 0.4;
+const D_WICK: f64 = 0.007; // wick diameter [m]
+const L_WICK: f64 = 0.0254; // wick length [m]
+
+const EMIS_GLOBE: f64 = 0.95;
+const ALB_GLOBE: f64 = 0.05;
+const D_GLOBE: f64 = 0.0508; // globe diameter [m]
+
+const EMIS_SFC: f64 = 0.999;
+const ALB_SFC: f64 = 0.45;
+
+const MIN_SPEED: f64 = 0.13; // minimum wind speed [m/s]
+const CZA_MIN: f64 = 0.00873; // below this the sun is treated as below the horizon
+const SOLVER: SolverOptions = SolverOptions {
+    tolerance: 0.02, // [K]
+    max_iterations: 50,
+};
+const RELAXATION: f64 = 0.1;
+
+/// Solar radiation inputs for the Liljegren model.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SolarRadiation {
+    /// Total solar irradiance on a horizontal surface in W m-2.
+    pub solar: f64,
+    /// Fraction of `solar` that is direct beam radiation, from 0 to 1.
+    pub direct_fraction: f64,
+    /// Cosine of the solar zenith angle (dimensionless).
+    pub cossza: f64,
+}
+
+impl SolarRadiation {
+    /// Returns the radiation with the direct beam folded into diffuse when the sun is at or below
+    /// the horizon, as in the reference implementation.
+    fn sanitized(self) -> SolarRadiation {
+        if self.cossza < CZA_MIN {
+            SolarRadiation {
+                direct_fraction: 0.0,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+/// Saturation vapour pressure over water in hPa, with the Buck (1981) enhancement factor for moist
+/// air at typical surface pressures.
+fn esat(t_k: f64) -> f64 {
+    let y = (t_k - 273.15) / (t_k - 32.18);
+    1.004 * 6.1121 * (17.502 * y).exp()
+}
+
+/// Dew point in Kelvin for vapour pressure `e` in hPa, the inverse of [`esat`].
+fn dew_point(e: f64) -> f64 {
+    let z = (e / (6.1121 * 1.004)).ln();
+    273.15 + 240.97 * z / (17.502 - z)
+}
+
+/// Dynamic viscosity of air in kg m-1 s-1.
+fn viscosity(t_k: f64) -> f64 {
+    let sigma = 3.617;
+    let eps_kappa = 97.0;
+    let tr = t_k / eps_kappa;
+    let omega = (tr - 2.9) / 0.4 * (-0.034) + 1.048;
+    2.6693e-6 * (M_AIR * t_k).sqrt() / (sigma * sigma * omega)
+}
+
+/// Thermal conductivity of air in W m-1 K-1.
+fn thermal_cond(t_k: f64) -> f64 {
+    (CP + 1.25 * R_AIR) * viscosity(t_k)
+}
+
+/// Diffusivity of water vapour in air in m2 s-1.
+fn diffusivity(t_k: f64, pressure_hpa: f64) -> f64 {
+    let pcrit13 = (36.4 * 218.0_f64).powf(1.0 / 3.0);
+    let tcrit512 = (132.0 * 647.3_f64).powf(5.0 / 12.0);
+    let tcrit12 = (132.0 * 647.3_f64).sqrt();
+    let mmix = (1.0 / M_AIR + 1.0 / M_H2O).sqrt();
+    3.64e-4 * (t_k / tcrit12).powf(2.334) * pcrit13 * tcrit512 * mmix / (pressure_hpa / 1013.25)
+        * 1.0e-4
+}
+
+/// Latent heat of evaporation of water in J kg-1.
+fn evap(t_k: f64) -> f64 {
+    (313.15 - t_k) / 30.0 * (-71100.0) + 2.4073e6
+}
+
+/// Emissivity of the atmosphere, from vapour pressure.
+fn emis_atm(t_k: f64, rh_fraction: f64) -> f64 {
+    let e = rh_fraction * esat(t_k);
+    0.575 * e.powf(0.143)
+}
+
+fn density(t_k: f64, pressure_hpa: f64) -> f64 {
+    pressure_hpa * 100.0 / (R_AIR * t_k)
+}
+
+/// Convective heat transfer coefficient for a cylinder in cross flow, in W m-2 K-1.
+fn h_cylinder_in_air(diameter: f64, t_k: f64, pressure_hpa: f64, speed: f64) -> f64 {
+    let a = 0.56;
+    let b = 0.281;
+    let c = 0.4;
+    let re = speed.max(MIN_SPEED) * density(t_k, pressure_hpa) * diameter / viscosity(t_k);
+    let nu = b * re.powf(1.0 - c) * PR.powf(1.0 - a);
+    nu * thermal_cond(t_k) / diameter
+}
+
+/// Convective heat transfer coefficient for flow around a sphere, in W m-2 K-1.
+fn h_sphere_in_air(diameter: f64, t_k: f64, pressure_hpa: f64, speed: f64) -> f64 {
+    let re = speed.max(MIN_SPEED) * density(t_k, pressure_hpa) * diameter / viscosity(t_k);
+    let nu = 2.0 + 0.6 * re.sqrt() * PR.powf(0.3333);
+    nu * thermal_cond(t_k) / diameter
+}
+
+/// Calculates natural wet bulb temperature with the Liljegren et al. (2008) heat balance model.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `radiation` is the incoming solar radiation.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the natural wet bulb temperature in Kelvin, or NaN if the iteration does
+/// not converge.
+///
+/// The wick is modelled as a 7 mm by 25.4 mm cylinder exchanging heat by convection, evaporation
+/// and long- and shortwave radiation with the sky and a ground surface at air temperature, solved
+/// by fixed-point iteration to within 0.02 K. Unlike the psychrometric wet bulb of
+/// [`crate::calculate_wbt`], it is raised by solar radiation and is not fully ventilated. The wind
+/// speed is scaled from 10 meters to the 2 meter height the model expects.
+///
+/// Reference: Liljegren et al. (2008) [https://doi.org/10.1080/15459620802310770](https://doi.org/10.1080/15459620802310770)
+pub fn calculate_natural_wet_bulb_liljegren(
+    t2_k: f64,
+    rh: f64,
+    va: f64,
+    radiation: SolarRadiation,
+    pressure_hpa: f64,
+) -> f64 {
+    let a = 0.56; // from Bedingfield and Drew
+    let radiation = radiation.sanitized();
+    let speed = scale_windspeed(va, 2.0);
+    let rh_fraction = rh / 100.0;
+
+    let t_sfc = t2_k;
+    let e_air = rh_fraction * esat(t2_k);
+
+    let direct_geometry = if radiation.direct_fraction > 0.0 {
+        let sza = radiation.cossza.acos();
+        radiation.direct_fraction * (sza.tan() / PI + 0.25 * D_WICK / L_WICK)
+    } else {
+        0.0
+    };
+    let absorbed_solar = (1.0 - ALB_WICK)
+        * radiation.solar
+        * ((1.0 - radiation.direct_fraction) * (1.0 + 0.25 * D_WICK / L_WICK)
+            + direct_geometry
+            + ALB_SFC);
+    let downwelling = 0.5 * (emis_atm(t2_k, rh_fraction) * t2_k.powi(4) + EMIS_SFC * t_sfc.powi(4));
+
+    // first guess is the dew point temperature, or the air temperature in perfectly dry air, which
+    // has no dew point
+    let twb_first = if e_air > 0.0 { dew_point(e_air) } else { t2_k };
+    let wick_balance = |twb: f64| {
+        let t_ref = 0.5 * (twb + t2_k); // evaluate properties at the average temperature
+        let h = h_cylinder_in_air(D_WICK, t_ref, pressure_hpa, speed);
+        let f_atm = STEFAN_BOLTZMANN * EMIS_WICK * (downwelling - twb.powi(4)) + absorbed_solar;
+        let e_wick = esat(twb);
+        let sc =
+            viscosity(t_ref) / (density(t_ref, pressure_hpa) * diffusivity(t_ref, pressure_hpa));
+        t2_k - evap(t_ref) / RATIO * (e_wick - e_air) / (pressure_hpa - e_wick) * (PR / sc).powf(a)
+            + f_atm / h
+    };
+    numeric::fixed_point(wick_balance, twb_first, RELAXATION, SOLVER).unwrap_or(f64::NAN)
+}
+
+/// Calculates black globe temperature with the Liljegren et al. (2008) heat balance model.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `radiation` is the incoming solar radiation.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the globe temperature in Kelvin, or NaN if the iteration does not converge.
+///
+/// The globe is the 50.8 mm globe of the reference implementation, rather than the standard
+/// 0.15 m globe of [`crate::calculate_bgt`], which needs a mean radiant temperature instead of
+/// solar radiation.
+///
+/// Reference: Liljegren et al. (2008) [https://doi.org/10.1080/15459620802310770](https://doi.org/10.1080/15459620802310770)
+pub fn calculate_globe_temperature_liljegren(
+    t2_k: f64,
+    rh: f64,
+    va: f64,
+    radiation: SolarRadiation,
+    pressure_hpa: f64,
+) -> f64 {
+    let radiation = radiation.sanitized();
+    let speed = scale_windspeed(va, 2.0);
+    let rh_fraction = rh / 100.0;
+
+    let t_sfc = t2_k;
+    let direct_geometry = if radiation.direct_fraction > 0.0 {
+        radiation.direct_fraction * (1.0 / (2.0 * radiation.cossza) - 1.0)
+    } else {
+        0.0
+    };
+    let absorbed_solar = radiation.solar / (2.0 * STEFAN_BOLTZMANN * EMIS_GLOBE)
+        * (1.0 - ALB_GLOBE)
+        * (direct_geometry + 1.0 + ALB_SFC);
+    let downwelling = 0.5 * (emis_atm(t2_k, rh_fraction) * t2_k.powi(4) + EMIS_SFC * t_sfc.powi(4));
+
+    let globe_balance = |tg: f64| {
+        let t_ref = 0.5 * (tg + t2_k);
+        let h = h_sphere_in_air(D_GLOBE, t_ref, pressure_hpa, speed);
+        (downwelling - h / (STEFAN_BOLTZMANN * EMIS_GLOBE) * (tg - t2_k) + absorbed_solar)
+            .powf(0.25)
+    };
+    numeric::fixed_point(globe_balance, t2_k, RELAXATION, SOLVER).unwrap_or(f64::NAN)
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) with the Liljegren et al. (2008) model.
+///
+/// Where `t2_k` is the 2m temperature in Kelvin.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `va` is the wind speed at 10 meters in m/s.
+///
+/// Where `radiation` is the incoming solar radiation.
+///
+/// Where `pressure_hpa` is the air pressure in hPa.
+///
+/// The return value is the wet bulb globe temperature in Kelvin,
+/// `0.7 Tnwb + 0.2 Tg + 0.1 Ta` from [`calculate_natural_wet_bulb_liljegren`] and
+/// [`calculate_globe_temperature_liljegren`]. This is the outdoor WBGT used in occupational safety,
+/// and is lower than [`crate::calculate_wbgt`], which takes the psychrometric wet bulb in place of
+/// the natural wet bulb.
+///
+/// Reference: Liljegren et al. (2008) [https://doi.org/10.1080/15459620802310770](https://doi.org/10.1080/15459620802310770)
+pub fn calculate_wbgt_liljegren(
+    t2_k: f64,
+    rh: f64,
+    va: f64,
+    radiation: SolarRadiation,
+    pressure_hpa: f64,
+) -> f64 {
+    let tnwb = calculate_natural_wet_bulb_liljegren(t2_k, rh, va, radiation, pressure_hpa);
+    let tg = calculate_globe_temperature_liljegren(t2_k, rh, va, radiation, pressure_hpa);
+    0.7 * tnwb + 0.2 * tg + 0.1 * t2_k
+}
@@ -0,0 +1,262 @@
+//! One-dimensional root finders and fixed-point iteration shared by the iterative calculations.
+
+use crate::ThermofeelError;
+
+/// Stopping criteria for the root finders.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct SolverOptions {
+    /// The absolute tolerance on the root. Zero iterates until the bracket cannot be split further.
+    pub(crate) tolerance: f64,
+    /// The most iterations allowed before giving up.
+    pub(crate) max_iterations: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-10,
+            max_iterations: 100,
+        }
+    }
+}
+
+fn check_bracket(f_lo: f64, f_hi: f64, lo: f64, hi: f64) -> Result<(), ThermofeelError> {
+    if f_lo.is_nan() || f_hi.is_nan() || (f_lo < 0.0) == (f_hi < 0.0) {
+        Err(ThermofeelError::RootNotBracketed { lo, hi })
+    } else {
+        Ok(())
+    }
+}
+
+/// Finds a root of `f` between `lo` and `hi` by bisection.
+///
+/// Where `f` is the function whose root is wanted.
+///
+/// Where `lo` and `hi` bracket the root, with `f(lo)` and `f(hi)` of opposite signs.
+///
+/// Where `options` gives the tolerance and iteration cap.
+///
+/// The return value is the root, or [`ThermofeelError::RootNotBracketed`] if `f` has the same sign
+/// (or is NaN) at both ends, or [`ThermofeelError::NoConvergence`] if the bracket is still wider
+/// than the tolerance after `max_iterations` halvings. Bisection always converges once bracketed,
+/// halving the bracket each iteration, so about 40 iterations reach 1e-10 K from a 100 K bracket.
+pub(crate) fn bisect(
+    mut f: impl FnMut(f64) -> f64,
+    lo: f64,
+    hi: f64,
+    options: SolverOptions,
+) -> Result<f64, ThermofeelError> {
+    let (mut lo, mut hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+    let (f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo == 0.0 {
+        return Ok(lo);
+    }
+    if f_hi == 0.0 {
+        return Ok(hi);
+    }
+    check_bracket(f_lo, f_hi, lo, hi)?;
+
+    let lo_negative = f_lo < 0.0;
+    for _ in 0..options.max_iterations {
+        let mid = 0.5 * (lo + hi);
+        if hi - lo <= options.tolerance || mid <= lo || mid >= hi {
+            return Ok(mid);
+        }
+        let f_mid = f(mid);
+        if f_mid == 0.0 {
+            return Ok(mid);
+        }
+        if (f_mid < 0.0) == lo_negative {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Err(ThermofeelError::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
+
+/// Finds a root of `f` between `lo` and `hi` by Newton's method, falling back to bisection.
+///
+/// Where `f` is the function whose root is wanted.
+///
+/// Where `df` is the derivative of `f`.
+///
+/// Where `lo` and `hi` bracket the root, with `f(lo)` and `f(hi)` of opposite signs.
+///
+/// Where `options` gives the tolerance and iteration cap.
+///
+/// The return value is the root, with the same errors as [`bisect`]. Each iteration takes a Newton
+/// step from the current estimate, which converges quadratically close to the root, but bisects
+/// the bracket instead whenever the step would leave it or the derivative is zero or NaN, so it is
+/// as robust as bisection. Converges once a step is smaller than the tolerance.
+pub(crate) fn newton_bisect(
+    mut f: impl FnMut(f64) -> f64,
+    mut df: impl FnMut(f64) -> f64,
+    lo: f64,
+    hi: f64,
+    options: SolverOptions,
+) -> Result<f64, ThermofeelError> {
+    let (mut lo, mut hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+    let (f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo == 0.0 {
+        return Ok(lo);
+    }
+    if f_hi == 0.0 {
+        return Ok(hi);
+    }
+    check_bracket(f_lo, f_hi, lo, hi)?;
+
+    let lo_negative = f_lo < 0.0;
+    let mut x = 0.5 * (lo + hi);
+    for _ in 0..options.max_iterations {
+        let fx = f(x);
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        if (fx < 0.0) == lo_negative {
+            lo = x;
+        } else {
+            hi = x;
+        }
+
+        let newton = x - fx / df(x);
+        let next = if newton > lo && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+        if (next - x).abs() <= options.tolerance || hi - lo <= options.tolerance {
+            return Ok(next);
+        }
+        x = next;
+    }
+    Err(ThermofeelError::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
+
+/// Finds a fixed point of `g`, where `g(x) = x`, by relaxed iteration from `x0`.
+///
+/// Where `g` is the update, giving the next estimate from the current one.
+///
+/// Where `x0` is the first estimate.
+///
+/// Where `relaxation` is the fraction of each update taken, from 0 to 1. One takes the update as it
+/// is; smaller values damp oscillation at the cost of more iterations.
+///
+/// Where `options` gives the tolerance and iteration cap.
+///
+/// The return value is the update `g(x)` from the first estimate `x` it moves by no more than the
+/// tolerance, or [`ThermofeelError::NoConvergence`] after `max_iterations` updates. Between
+/// updates the estimate moves to `x + relaxation (g(x) - x)`. A NaN estimate never converges.
+pub(crate) fn fixed_point(
+    mut g: impl FnMut(f64) -> f64,
+    x0: f64,
+    relaxation: f64,
+    options: SolverOptions,
+) -> Result<f64, ThermofeelError> {
+    let mut x = x0;
+    for _ in 0..options.max_iterations {
+        let next = g(x);
+        if (next - x).abs() <= options.tolerance {
+            return Ok(next);
+        }
+        x = (1.0 - relaxation) * x + relaxation * next;
+    }
+    Err(ThermofeelError::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_bisect_finds_known_roots() {
+        let sqrt2 = bisect(|x| x * x - 2.0, 0.0, 2.0, SolverOptions::default()).unwrap();
+        assert_relative_eq!(sqrt2, 2.0_f64.sqrt(), epsilon = 1e-10);
+
+        // cos(x) = x has its root at the Dottie number.
+        let dottie = bisect(|x| x.cos() - x, 1.0, 0.0, SolverOptions::default()).unwrap();
+        assert_relative_eq!(dottie, 0.739_085_133_215_160_6, epsilon = 1e-10);
+
+        assert_eq!(
+            bisect(|x| x - 1.0, 1.0, 3.0, SolverOptions::default()),
+            Ok(1.0)
+        );
+    }
+
+    #[test]
+    fn test_newton_bisect_finds_known_roots() {
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            max_iterations: 50,
+        };
+        let sqrt2 = newton_bisect(|x| x * x - 2.0, |x| 2.0 * x, 0.0, 2.0, options).unwrap();
+        assert_relative_eq!(sqrt2, 2.0_f64.sqrt(), epsilon = 1e-12);
+
+        // Newton from the midpoint of [0, 1] overshoots out of the bracket for x^10 - 0.5 and
+        // must bisect.
+        let root = newton_bisect(
+            |x| x.powi(10) - 0.5,
+            |x| 10.0 * x.powi(9),
+            0.0,
+            1.0,
+            options,
+        )
+        .unwrap();
+        assert_relative_eq!(root, 0.5_f64.powf(0.1), epsilon = 1e-12);
+
+        // The first estimate, x = 1, is a stationary point, so it bisects rather than dividing by
+        // zero.
+        let f = |x: f64| x.powi(3) - 3.0 * x - 1.0;
+        let root = newton_bisect(f, |x| 3.0 * x * x - 3.0, 0.0, 2.0, options).unwrap();
+        assert_relative_eq!(root, 1.879_385_241_571_817, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_solvers_report_errors() {
+        let options = SolverOptions::default();
+        assert_eq!(
+            bisect(|x| x * x + 1.0, -1.0, 1.0, options),
+            Err(ThermofeelError::RootNotBracketed { lo: -1.0, hi: 1.0 })
+        );
+        assert_eq!(
+            newton_bisect(|_| f64::NAN, |_| 1.0, 0.0, 1.0, options),
+            Err(ThermofeelError::RootNotBracketed { lo: 0.0, hi: 1.0 })
+        );
+
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            max_iterations: 5,
+        };
+        assert_eq!(
+            bisect(|x| x - 0.3, 0.0, 1.0, options),
+            Err(ThermofeelError::NoConvergence { iterations: 5 })
+        );
+    }
+
+    #[test]
+    fn test_fixed_point_relaxes_updates() {
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            max_iterations: 100,
+        };
+        let dottie = fixed_point(f64::cos, 1.0, 1.0, options).unwrap();
+        assert_relative_eq!(dottie, 0.739_085_133_215_160_6, epsilon = 1e-11);
+
+        // Undamped, x -> 3 - x jumps between 1 and 2 forever; half steps land on 1.5 at once.
+        assert!(fixed_point(|x| 3.0 - x, 1.0, 1.0, options).is_err());
+        assert_eq!(fixed_point(|x| 3.0 - x, 1.0, 0.5, options), Ok(1.5));
+
+        assert_eq!(
+            fixed_point(|x| x + f64::NAN, 0.0, 1.0, options),
+            Err(ThermofeelError::NoConvergence { iterations: 100 })
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::cell::{Cell, OnceCell};
+use std::collections::HashMap;
+
+use crate::{
+    MeteoColumns, calculate_humidex, calculate_relative_humidity_percent, calculate_utci,
+    calculate_wbt, relative_humidity_to_vapour_pressure, wbgt_from_relative_humidity,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum PipelineIndex {
+    Utci,
+    Wbgt,
+    Humidex,
+    Wbt,
+}
+
+impl PipelineIndex {
+    fn name(self) -> &'static str {
+        match self {
+            PipelineIndex::Utci => "utci",
+            PipelineIndex::Wbgt => "wbgt",
+            PipelineIndex::Humidex => "humidex",
+            PipelineIndex::Wbt => "wbt",
+        }
+    }
+}
+
+/// Computes a chosen subset of indices over [`MeteoColumns`], sharing intermediates between them.
+///
+/// Register indices with the `with_*` methods, then call [`Pipeline::run`]. The result maps each
+/// index name (`"utci"`, `"wbgt"`, `"humidex"`, `"wbt"`) to one value in Kelvin per point, equal to
+/// the matching `MeteoColumns::compute_*` method. The one difference is that, when radiation columns
+/// are present, UTCI and WBGT use the mean radiant temperature computed from them rather than `mrt`.
+///
+/// Relative humidity, vapour pressure and, when radiation columns are present, mean radiant
+/// temperature are each computed at most once per run, however many selected indices need them,
+/// and not at all if none do. See [`Intermediates`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pipeline {
+    indices: Vec<PipelineIndex>,
+}
+
+impl Pipeline {
+    /// Creates a pipeline with no indices selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(mut self, index: PipelineIndex) -> Self {
+        if !self.indices.contains(&index) {
+            self.indices.push(index);
+        }
+        self
+    }
+
+    /// Selects the Universal Thermal Climate Index (UTCI), stored as `"utci"`.
+    pub fn with_utci(self) -> Self {
+        self.with(PipelineIndex::Utci)
+    }
+
+    /// Selects Wet Bulb Globe Temperature (WBGT), stored as `"wbgt"`.
+    pub fn with_wbgt(self) -> Self {
+        self.with(PipelineIndex::Wbgt)
+    }
+
+    /// Selects Humidex, stored as `"humidex"`.
+    pub fn with_humidex(self) -> Self {
+        self.with(PipelineIndex::Humidex)
+    }
+
+    /// Selects Wet Bulb Temperature, stored as `"wbt"`.
+    pub fn with_wbt(self) -> Self {
+        self.with(PipelineIndex::Wbt)
+    }
+
+    /// Calculates every selected index for each point of `columns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn run(&self, columns: &MeteoColumns) -> HashMap<&'static str, Vec<f64>> {
+        self.run_with(&Intermediates::new(columns))
+    }
+
+    /// Calculates every selected index using, and filling, the given intermediates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the same length.
+    pub fn run_with(&self, intermediates: &Intermediates) -> HashMap<&'static str, Vec<f64>> {
+        let c = intermediates.columns;
+        c.assert_lengths();
+
+        self.indices
+            .iter()
+            .map(|&index| {
+                let values = match index {
+                    PipelineIndex::Utci => {
+                        let (mrt, eh) = (
+                            intermediates.mean_radiant_temperature(),
+                            intermediates.vapour_pressure(),
+                        );
+                        (0..c.len())
+                            .map(|i| calculate_utci(c.t2_k[i], c.va[i], mrt[i], None, Some(eh[i])))
+                            .collect()
+                    }
+                    PipelineIndex::Wbgt => {
+                        let (mrt, rh) = (
+                            intermediates.mean_radiant_temperature(),
+                            intermediates.relative_humidity(),
+                        );
+                        (0..c.len())
+                            .map(|i| wbgt_from_relative_humidity(c.t2_k[i], mrt[i], c.va[i], rh[i]))
+                            .collect()
+                    }
+                    PipelineIndex::Humidex => (0..c.len())
+                        .map(|i| calculate_humidex(c.t2_k[i], c.td_k[i]))
+                        .collect(),
+                    PipelineIndex::Wbt => {
+                        let rh = intermediates.relative_humidity();
+                        (0..c.len())
+                            .map(|i| calculate_wbt(c.t2_k[i], rh[i]))
+                            .collect()
+                    }
+                };
+                (index.name(), values)
+            })
+            .collect()
+    }
+}
+
+/// Intermediate columns shared by the indices of a [`Pipeline`], each computed on first use.
+#[derive(Debug)]
+pub struct Intermediates<'a> {
+    columns: &'a MeteoColumns,
+    relative_humidity: OnceCell<Vec<f64>>,
+    vapour_pressure: OnceCell<Vec<f64>>,
+    mean_radiant_temperature: OnceCell<Vec<f64>>,
+    evaluations: Cell<usize>,
+}
+
+impl<'a> Intermediates<'a> {
+    /// Creates empty intermediates for `columns`.
+    pub fn new(columns: &'a MeteoColumns) -> Self {
+        Self {
+            columns,
+            relative_humidity: OnceCell::new(),
+            vapour_pressure: OnceCell::new(),
+            mean_radiant_temperature: OnceCell::new(),
+            evaluations: Cell::new(0),
+        }
+    }
+
+    fn evaluate(&self, f: impl FnOnce() -> Vec<f64>) -> Vec<f64> {
+        self.evaluations.set(self.evaluations.get() + 1);
+        f()
+    }
+
+    /// Returns the relative humidity percentage for each point, as
+    /// [`calculate_relative_humidity_percent`].
+    pub fn relative_humidity(&self) -> &[f64] {
+        self.relative_humidity.get_or_init(|| {
+            self.evaluate(|| {
+                let c = self.columns;
+                (0..c.len())
+                    .map(|i| calculate_relative_humidity_percent(c.t2_k[i], c.td_k[i]))
+                    .collect()
+            })
+        })
+    }
+
+    /// Returns the water vapour pressure in hPa for each point, as
+    /// [`relative_humidity_to_vapour_pressure`] of [`Intermediates::relative_humidity`].
+    pub fn vapour_pressure(&self) -> &[f64] {
+        self.vapour_pressure.get_or_init(|| {
+            let rh = self.relative_humidity();
+            self.evaluate(|| {
+                let t2_k = &self.columns.t2_k;
+                (0..t2_k.len())
+                    .map(|i| relative_humidity_to_vapour_pressure(t2_k[i], rh[i]))
+                    .collect()
+            })
+        })
+    }
+
+    /// Returns the mean radiant temperature in Kelvin for each point: computed from the radiation
+    /// columns if there are any, as [`MeteoColumns::compute_mean_radiant_temperature`], otherwise
+    /// the `mrt` column itself.
+    pub fn mean_radiant_temperature(&self) -> &[f64] {
+        if self.columns.radiation.is_none() {
+            return &self.columns.mrt;
+        }
+        self.mean_radiant_temperature.get_or_init(|| {
+            self.evaluate(|| {
+                self.columns
+                    .compute_mean_radiant_temperature()
+                    .unwrap_or_default()
+            })
+        })
+    }
+
+    /// Returns the number of intermediate columns computed so far.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.get()
+    }
+}
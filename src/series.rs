@@ -0,0 +1,100 @@
+/// Returns `max(0, x)`, keeping NaN so that missing days are not silently dropped.
+fn positive_part(x: f64) -> f64 {
+    if x < 0.0 { 0.0 } else { x }
+}
+
+/// Calculates cooling degree-days over a series of daily mean temperatures.
+///
+/// Where `daily_mean_k` is the daily mean 2m temperature for each day in Kelvin.
+///
+/// Where `base_k` is the base temperature in Kelvin above which cooling is assumed to be required.
+///
+/// The return value is the cooling degree-days in Kelvin days, which are equal to Celsius degree-days.
+/// The result is NaN if any day is NaN.
+///
+/// Each day contributes `max(0, T - base)`. The base temperature is chosen by the caller; common
+/// conventions are 18.3°C (65°F) in the US and 18°C or 22°C in Europe.
+///
+/// Reference: [https://www.weather.gov/key/climate_heat_cool](https://www.weather.gov/key/climate_heat_cool)
+pub fn calculate_cooling_degree_days(daily_mean_k: &[f64], base_k: f64) -> f64 {
+    daily_mean_k.iter().map(|t| positive_part(t - base_k)).sum()
+}
+
+/// Calculates heating degree-days over a series of daily mean temperatures.
+///
+/// Where `daily_mean_k` is the daily mean 2m temperature for each day in Kelvin.
+///
+/// Where `base_k` is the base temperature in Kelvin below which heating is assumed to be required.
+///
+/// The return value is the heating degree-days in Kelvin days, which are equal to Celsius degree-days.
+/// The result is NaN if any day is NaN.
+///
+/// Each day contributes `max(0, base - T)`. The base temperature is chosen by the caller; common
+/// conventions are 18.3°C (65°F) in the US and 15.5°C in the UK.
+///
+/// Reference: [https://www.weather.gov/key/climate_heat_cool](https://www.weather.gov/key/climate_heat_cool)
+pub fn calculate_heating_degree_days(daily_mean_k: &[f64], base_k: f64) -> f64 {
+    daily_mean_k.iter().map(|t| positive_part(base_k - t)).sum()
+}
+
+/// Finds every run of consecutive values above a threshold, with the peak of each.
+///
+/// Where `series_k` is an index, such as heat index or apparent temperature, in Kelvin at regular
+/// time steps, usually hourly.
+///
+/// Where `threshold_k` is the threshold in Kelvin that a value must exceed.
+///
+/// The return value is `(start, end, peak_k)` for each run, in order, where `start` and `end` are
+/// the indices of its first and last values, with `end` inclusive, and `peak_k` is its highest
+/// value. A run is a maximal stretch of values strictly above `threshold_k`, so a value equal to the
+/// threshold or NaN ends it, and a single value above the threshold is a run of one. The length of a
+/// run is `end - start + 1` time steps, the "danger time" spent above the threshold. Unlike
+/// [`detect_heatwave`] there is no minimum length.
+pub fn exceedance_runs(series_k: &[f64], threshold_k: f64) -> Vec<(usize, usize, f64)> {
+    let mut runs = Vec::new();
+    let mut current: Option<(usize, f64)> = None;
+
+    for (i, &value) in series_k.iter().enumerate() {
+        match (value > threshold_k, current) {
+            (true, None) => current = Some((i, value)),
+            (true, Some((s, peak))) => current = Some((s, peak.max(value))),
+            (false, Some((s, peak))) => {
+                runs.push((s, i - 1, peak));
+                current = None;
+            }
+            (false, None) => {}
+        }
+    }
+    if let Some((s, peak)) = current {
+        runs.push((s, series_k.len() - 1, peak));
+    }
+
+    runs
+}
+
+/// Finds heat-wave periods in a daily series of an index.
+///
+/// Where `series_k` is the daily value of an index, such as UTCI or apparent temperature, in Kelvin.
+///
+/// Where `threshold_k` is the threshold in Kelvin that a day must exceed.
+///
+/// Where `min_consecutive_days` is the minimum length of a heat wave in days.
+///
+/// The return value is the `(start, end)` index of each heat wave, with `end` inclusive, in order.
+/// A heat wave is a run of at least `min_consecutive_days` consecutive days strictly above
+/// `threshold_k`, the definition used by many national heat-health warning systems with a local
+/// threshold and a duration of two or three days. A NaN day ends a run. These are the runs of
+/// [`exceedance_runs`] that are long enough.
+pub fn detect_heatwave(
+    series_k: &[f64],
+    threshold_k: f64,
+    min_consecutive_days: usize,
+) -> Vec<(usize, usize)> {
+    let min_days = min_consecutive_days.max(1);
+
+    exceedance_runs(series_k, threshold_k)
+        .into_iter()
+        .filter(|&(start, end, _)| end - start + 1 >= min_days)
+        .map(|(start, end, _)| (start, end))
+        .collect()
+}
@@ -0,0 +1,78 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+const J2000_JULIAN_DAY: f64 = 2451545.0;
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Seconds from the Unix epoch to `datetime`, negative for earlier times.
+fn unix_seconds(datetime: SystemTime) -> f64 {
+    match datetime.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_secs_f64(),
+        Err(before) => -before.duration().as_secs_f64(),
+    }
+}
+
+/// Solar declination in radians and the equation of time in minutes, from NOAA's implementation of
+/// Meeus (1998), at `t` Julian centuries since J2000.
+fn declination_and_equation_of_time(t: f64) -> (f64, f64) {
+    let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anomaly = (357.52911 + t * (35999.05029 - 0.0001537 * t)).to_radians();
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let equation_of_centre = mean_anomaly.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * mean_anomaly).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * mean_anomaly).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude =
+        (mean_longitude + equation_of_centre - 0.00569 - 0.00478 * omega.sin()).to_radians();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+
+    let l0 = mean_longitude.to_radians();
+    let y = (obliquity / 2.0).tan().powi(2);
+    let equation_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccentricity * mean_anomaly.sin()
+            + 4.0 * eccentricity * y * mean_anomaly.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * mean_anomaly).sin())
+        .to_degrees();
+
+    (declination, equation_of_time)
+}
+
+/// Calculates the cosine of the solar zenith angle.
+///
+/// Where `latitude` is the latitude in degrees, positive north.
+///
+/// Where `longitude` is the longitude in degrees, positive east.
+///
+/// Where `datetime_utc` is the time of interest. [`SystemTime`] is an absolute instant, so there is
+/// no time zone to get wrong; build one from a UTC timestamp with `UNIX_EPOCH + Duration`.
+///
+/// The return value is the cosine of the geometric solar zenith angle (dimensionless), the
+/// `cossza` input of the radiation functions. It is negative when the sun is below the horizon,
+/// which callers needing daytime values should clamp to zero. No atmospheric refraction is applied,
+/// so the sun appears to rise when this is about -0.0145 (a zenith of 90.833°), not 0.
+///
+/// These are the equations of the NOAA solar calculator, after Meeus (1998). Their stated accuracy
+/// is about 0.01° in solar position for dates between 1800 and 2100, with error growing outside it.
+///
+/// Reference: Meeus (1998), Astronomical Algorithms, 2nd edition, chapters 25 and 28
+///
+/// See also: [https://gml.noaa.gov/grad/solcalc/calcdetails.html](https://gml.noaa.gov/grad/solcalc/calcdetails.html)
+pub fn calculate_cos_solar_zenith(latitude: f64, longitude: f64, datetime_utc: SystemTime) -> f64 {
+    let seconds = unix_seconds(datetime_utc);
+    let julian_day = seconds / SECONDS_PER_DAY + UNIX_EPOCH_JULIAN_DAY;
+    let t = (julian_day - J2000_JULIAN_DAY) / 36525.0;
+    let (declination, equation_of_time) = declination_and_equation_of_time(t);
+
+    let minutes_utc = seconds.rem_euclid(SECONDS_PER_DAY) / 60.0;
+    let true_solar_time = minutes_utc + equation_of_time + 4.0 * longitude;
+    let hour_angle = (true_solar_time / 4.0 - 180.0).to_radians();
+
+    let latitude = latitude.to_radians();
+    latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.cos()
+}
@@ -0,0 +1,17 @@
+//! Optional `tracing` instrumentation, compiled away without the `trace` feature.
+
+/// Enters a trace-level span for the rest of the enclosing block.
+macro_rules! trace_span {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "trace")]
+        let _span = tracing::trace_span!($($arg)*).entered();
+    };
+}
+
+/// Emits a trace-level event, with intermediate values as fields.
+macro_rules! trace_values {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "trace")]
+        tracing::trace!($($arg)*);
+    };
+}
@@ -0,0 +1,197 @@
+use crate::constants::ZERO_CELSIUS_K;
+use crate::numeric::{self, SolverOptions};
+use crate::{celsius_to_kelvin, kelvin_to_celsius, normalize_relative_humidity};
+
+const BODY_WEIGHT: f64 = 69.9; // [kg]
+const BODY_SURFACE_AREA: f64 = 1.8258; // DuBois area [m2]
+const MET_FACTOR: f64 = 58.2; // [W m-2 met-1]
+const SBC: f64 = 5.6697e-8; // Stefan-Boltzmann constant as in the reference code [W m-2 K-4]
+const CSW: f64 = 170.0; // sweating driving coefficient [g m-2 h-1 K-1]
+const CDIL: f64 = 120.0; // vasodilation coefficient [L m-2 h-1 K-1]
+const CSTR: f64 = 0.5; // vasoconstriction coefficient [K-1]
+const CLOTHING_EMISSIVITY: f64 = 0.95;
+const RADIATION_AREA_RATIO: f64 = 0.73; // radiating fraction of the skin area, standing
+const SKIN_NEUTRAL_C: f64 = 33.7;
+const CORE_NEUTRAL_C: f64 = 36.8;
+const BODY_NEUTRAL_C: f64 = 36.49;
+const SKIN_BLOOD_FLOW_NEUTRAL: f64 = 6.3; // [L m-2 h-1]
+const PRESSURE_KPA: f64 = 101.325;
+const MINUTES: usize = 60;
+const CLOTHING_SOLVER: SolverOptions = SolverOptions {
+    tolerance: 0.01, // [K]
+    max_iterations: 100,
+};
+
+/// Saturation vapour pressure in Torr at `t_c` in Celsius, as in the reference code.
+fn saturation_vapour_pressure_torr(t_c: f64) -> f64 {
+    (18.6686 - 4030.183 / (t_c + 235.0)).exp()
+}
+
+/// Outputs of the Gagge two-node model after one hour of exposure.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TwoNodeResult {
+    /// Mean skin temperature in Kelvin.
+    pub skin_temperature: f64,
+    /// Core temperature in Kelvin.
+    pub core_temperature: f64,
+    /// Skin wettedness, the fraction of the skin covered in sweat (dimensionless), from 0.06 up to
+    /// the limit given by [`calculate_two_node_model`].
+    pub skin_wettedness: f64,
+    /// Regulatory sweat rate in g m-2 h-1.
+    pub sweat_rate: f64,
+}
+
+/// Calculates skin and core temperature, skin wettedness and sweat rate with the Gagge two-node
+/// model.
+///
+/// Where `ta_k` is the air temperature in Kelvin.
+///
+/// Where `mrt_k` is the mean radiant temperature in Kelvin.
+///
+/// Where `va` is the air speed around the body in m/s. Unlike the outdoor indices this is not a
+/// 10m wind speed and is not scaled; speeds below 0.1 m/s are treated as 0.1 m/s.
+///
+/// Where `rh` is the relative humidity percentage.
+///
+/// Where `met` is the metabolic rate in met (1 met = 58.2 W m-2), with no external work.
+///
+/// Where `clo` is the clothing insulation in clo (1 clo = 0.155 m2 K W-1).
+///
+/// The return value is a [`TwoNodeResult`] for an average adult (69.9 kg, 1.83 m2) at sea-level
+/// pressure. The body is modelled as a core and a skin shell, starting from their neutral
+/// temperatures of 36.8°C and 33.7°C, and stepped forward one minute at a time for an hour. In each
+/// step the clothing surface temperature is found by fixed-point iteration to within 0.01 K, with
+/// the radiative exchange of a standing body (clothing emissivity 0.95, 73% of the skin area
+/// radiating) as in pythermalcomfort's `two_nodes`. Then the heat balances of both nodes update
+/// their temperatures, and the thermoregulatory signals set skin blood flow, sweating and shivering
+/// for the next step. Skin wettedness is capped at 0.59 when clothed, or 0.38 when `clo` is zero.
+/// These are the inputs to the Standard Effective Temperature and, as in the reference code, are
+/// not otherwise validated; relative humidity outside 0% to 100% is clamped by
+/// [`normalize_relative_humidity`]. Every field is NaN if the clothing temperature iteration does
+/// not converge within 100 iterations, as happens when any input is NaN.
+///
+/// Reference: Gagge, Fobelets and Berglund (1986), A standard predictive index of human response to
+/// the thermal environment, ASHRAE Transactions 92(2B), pp. 709-731
+///
+/// See also: ANSI/ASHRAE Standard 55, Normative Appendix D, which lists the reference code
+pub fn calculate_two_node_model(
+    ta_k: f64,
+    mrt_k: f64,
+    va: f64,
+    rh: f64,
+    met: f64,
+    clo: f64,
+) -> TwoNodeResult {
+    let ta = kelvin_to_celsius(ta_k);
+    let tr = kelvin_to_celsius(mrt_k);
+    let vapour_pressure =
+        normalize_relative_humidity(rh) * saturation_vapour_pressure_torr(ta) / 100.0;
+    let air_speed = va.max(0.1);
+
+    let pressure_atm = PRESSURE_KPA * 0.009869;
+    let rcl = 0.155 * clo;
+    let facl = 1.0 + 0.15 * clo;
+    let lr = 2.2 / pressure_atm; // Lewis ratio
+    let rm = met * MET_FACTOR;
+    let (wcrit, icl) = if clo <= 0.0 {
+        (0.38, 1.0)
+    } else {
+        (0.59, 0.45)
+    };
+
+    let chc = (3.0 * pressure_atm.powf(0.53)).max(8.600001 * (air_speed * pressure_atm).powf(0.53));
+    let mut chr = 4.7;
+    let mut ctc = chr + chc;
+    let mut ra = 1.0 / (facl * ctc);
+    let mut top = (chr * tr + chc * ta) / ctc;
+
+    let mut t_skin = SKIN_NEUTRAL_C;
+    let mut t_core = CORE_NEUTRAL_C;
+    let mut skin_blood_flow = SKIN_BLOOD_FLOW_NEUTRAL;
+    let mut alfa = 0.1;
+    let mut esk = 0.1 * met;
+    let mut m = rm;
+    let mut t_clothing = top + (t_skin - top) / (ctc * (ra + rcl));
+    let mut wettedness = 0.06;
+    let mut sweat_rate = 0.0;
+
+    for _ in 0..MINUTES {
+        let clothing_balance = |t_clothing: f64| {
+            chr = 4.0
+                * CLOTHING_EMISSIVITY
+                * SBC
+                * ((t_clothing + tr) / 2.0 + ZERO_CELSIUS_K).powi(3)
+                * RADIATION_AREA_RATIO;
+            ctc = chr + chc;
+            ra = 1.0 / (facl * ctc);
+            top = (chr * tr + chc * ta) / ctc;
+            (ra * t_skin + rcl * top) / (ra + rcl)
+        };
+        match numeric::fixed_point(clothing_balance, t_clothing, 1.0, CLOTHING_SOLVER) {
+            Ok(converged) => t_clothing = converged,
+            Err(_) => {
+                return TwoNodeResult {
+                    skin_temperature: f64::NAN,
+                    core_temperature: f64::NAN,
+                    skin_wettedness: f64::NAN,
+                    sweat_rate: f64::NAN,
+                };
+            }
+        }
+
+        // Heat balance of the two nodes.
+        let dry = (t_skin - top) / (ra + rcl);
+        let hfcs = (t_core - t_skin) * (5.28 + 1.163 * skin_blood_flow);
+        let eres = 0.0023 * m * (44.0 - vapour_pressure);
+        let cres = 0.0014 * m * (34.0 - ta);
+        let scr = m - hfcs - eres - cres;
+        let ssk = hfcs - dry - esk;
+        let tcsk = 0.97 * alfa * BODY_WEIGHT;
+        let tccr = 0.97 * (1.0 - alfa) * BODY_WEIGHT;
+        t_skin += ssk * BODY_SURFACE_AREA / (tcsk * 60.0);
+        t_core += scr * BODY_SURFACE_AREA / (tccr * 60.0);
+        let t_body = alfa * t_skin + (1.0 - alfa) * t_core;
+
+        // Thermoregulatory signals.
+        let skin_signal = t_skin - SKIN_NEUTRAL_C;
+        let (warm_skin, cold_skin) = (skin_signal.max(0.0), (-skin_signal).max(0.0));
+        let core_signal = t_core - CORE_NEUTRAL_C;
+        let (warm_core, cold_core) = (core_signal.max(0.0), (-core_signal).max(0.0));
+        let warm_body = (t_body - BODY_NEUTRAL_C).max(0.0);
+
+        skin_blood_flow = ((SKIN_BLOOD_FLOW_NEUTRAL + CDIL * warm_core) / (1.0 + CSTR * cold_skin))
+            .clamp(0.5, 90.0);
+        sweat_rate = (CSW * warm_body * (warm_skin / 10.7).exp()).min(500.0);
+
+        // Evaporation from the skin.
+        let mut ersw = 0.68 * sweat_rate;
+        let rea = 1.0 / (lr * facl * chc);
+        let recl = rcl / (lr * icl);
+        let emax = (saturation_vapour_pressure_torr(t_skin) - vapour_pressure) / (rea + recl);
+        let mut prsw = ersw / emax;
+        wettedness = 0.06 + 0.94 * prsw;
+        let mut edif = wettedness * emax - ersw;
+        if wettedness > wcrit {
+            wettedness = wcrit;
+            prsw = wcrit / 0.94;
+            ersw = prsw * emax;
+            edif = 0.06 * (1.0 - prsw) * emax;
+        }
+        if emax < 0.0 {
+            edif = 0.0;
+            ersw = 0.0;
+            wettedness = wcrit;
+        }
+        esk = ersw + edif;
+
+        m = rm + 19.4 * cold_skin * cold_core; // shivering
+        alfa = 0.0417737 + 0.7451833 / (skin_blood_flow + 0.585417);
+    }
+
+    TwoNodeResult {
+        skin_temperature: celsius_to_kelvin(t_skin),
+        core_temperature: celsius_to_kelvin(t_core),
+        skin_wettedness: wettedness,
+        sweat_rate,
+    }
+}
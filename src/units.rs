@@ -0,0 +1,118 @@
+use crate::{
+    calculate_apparent_temperature, calculate_bgt, calculate_heat_index_adjusted,
+    calculate_heat_index_simplified, calculate_humidex, calculate_normal_effective_temperature,
+    calculate_utci, calculate_wbgt, calculate_wbgt_simple, calculate_wbt, calculate_wind_chill,
+    kelvin_to_celsius, kelvin_to_fahrenheit,
+};
+
+/// The temperature unit returned by the `_in` variants of the indices.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutputUnit {
+    /// Kelvin, as returned by the indices themselves.
+    #[default]
+    Kelvin,
+    /// Degrees Celsius.
+    Celsius,
+    /// Degrees Fahrenheit.
+    Fahrenheit,
+}
+
+impl OutputUnit {
+    /// Converts a temperature in Kelvin to this unit.
+    pub fn from_kelvin(self, t_k: f64) -> f64 {
+        match self {
+            OutputUnit::Kelvin => t_k,
+            OutputUnit::Celsius => kelvin_to_celsius(t_k),
+            OutputUnit::Fahrenheit => kelvin_to_fahrenheit(t_k),
+        }
+    }
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_utci`].
+pub fn calculate_utci_in(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    td_k: Option<f64>,
+    eh_pa: Option<f64>,
+    unit: OutputUnit,
+) -> f64 {
+    unit.from_kelvin(calculate_utci(t2_k, va, mrt, td_k, eh_pa))
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_wbgt`].
+pub fn calculate_wbgt_in(t2_k: f64, mrt: f64, va: f64, td_k: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_wbgt(t2_k, mrt, va, td_k))
+}
+
+/// Calculates simplified Wet Bulb Globe Temperature (WBGT) in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_wbgt_simple`].
+pub fn calculate_wbgt_simple_in(t2_k: f64, rh: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_wbgt_simple(t2_k, rh))
+}
+
+/// Calculates Wet Bulb Temperature in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_wbt`].
+pub fn calculate_wbt_in(t2_k: f64, rh: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_wbt(t2_k, rh))
+}
+
+/// Calculates Globe Temperature in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_bgt`].
+pub fn calculate_bgt_in(t2_k: f64, mrt: f64, va: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_bgt(t2_k, mrt, va))
+}
+
+/// Calculates Humidex in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_humidex`].
+pub fn calculate_humidex_in(t2_k: f64, td_k: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_humidex(t2_k, td_k))
+}
+
+/// Calculates Normal Effective Temperature (NET) in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_normal_effective_temperature`].
+pub fn calculate_normal_effective_temperature_in(
+    t2_k: f64,
+    va: f64,
+    rh: f64,
+    unit: OutputUnit,
+) -> f64 {
+    unit.from_kelvin(calculate_normal_effective_temperature(t2_k, va, rh))
+}
+
+/// Calculates Apparent Temperature in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_apparent_temperature`].
+pub fn calculate_apparent_temperature_in(t2_k: f64, va: f64, rh: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_apparent_temperature(t2_k, va, rh))
+}
+
+/// Calculates Wind Chill in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_wind_chill`].
+pub fn calculate_wind_chill_in(t2_k: f64, va: f64, unit: OutputUnit) -> f64 {
+    unit.from_kelvin(calculate_wind_chill(t2_k, va))
+}
+
+/// Calculates Heat Index using a simplified method in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_heat_index_simplified`].
+pub fn calculate_heat_index_simplified_in(t2_k: f64, rh: f64, unit: OutputUnit) -> Option<f64> {
+    calculate_heat_index_simplified(t2_k, rh).map(|hi| unit.from_kelvin(hi))
+}
+
+/// Calculates Heat Index with adjustments in the given unit.
+///
+/// Inputs are in Kelvin, as for [`calculate_heat_index_adjusted`].
+pub fn calculate_heat_index_adjusted_in(t2_k: f64, td_k: f64, unit: OutputUnit) -> Option<f64> {
+    calculate_heat_index_adjusted(t2_k, td_k).map(|hi| unit.from_kelvin(hi))
+}
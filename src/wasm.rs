@@ -0,0 +1,49 @@
+//! WebAssembly bindings, enabled with the `wasm` feature.
+//!
+//! Each function wraps the crate function of the same name and is exported to JavaScript under
+//! that name. Functions that return `Option<f64>` in Rust return `null` in JavaScript when no
+//! value applies.
+
+use wasm_bindgen::prelude::*;
+
+fn option_to_js(value: Option<f64>) -> JsValue {
+    value.map_or(JsValue::NULL, JsValue::from_f64)
+}
+
+/// Calculates the Universal Thermal Climate Index (UTCI) in Kelvin, or `null` if neither `td_k` nor
+/// `eh_pa` is provided.
+///
+/// [`crate::calculate_utci`] panics without humidity, which would abort the whole module, so the
+/// inputs are checked here first.
+#[wasm_bindgen]
+pub fn calculate_utci(
+    t2_k: f64,
+    va: f64,
+    mrt: f64,
+    td_k: Option<f64>,
+    eh_pa: Option<f64>,
+) -> JsValue {
+    let utci = match (td_k, eh_pa) {
+        (None, None) => None,
+        _ => Some(crate::calculate_utci(t2_k, va, mrt, td_k, eh_pa)),
+    };
+    option_to_js(utci)
+}
+
+/// Calculates Wet Bulb Globe Temperature (WBGT) in Kelvin.
+#[wasm_bindgen]
+pub fn calculate_wbgt(t2_k: f64, mrt: f64, va: f64, td_k: f64) -> f64 {
+    crate::calculate_wbgt(t2_k, mrt, va, td_k)
+}
+
+/// Calculates Humidex in Kelvin.
+#[wasm_bindgen]
+pub fn calculate_humidex(t2_k: f64, td_k: f64) -> f64 {
+    crate::calculate_humidex(t2_k, td_k)
+}
+
+/// Calculates the simplified heat index in Kelvin, or `null` if the temperature is too low.
+#[wasm_bindgen]
+pub fn calculate_heat_index_simplified(t2_k: f64, rh: f64) -> JsValue {
+    option_to_js(crate::calculate_heat_index_simplified(t2_k, rh))
+}
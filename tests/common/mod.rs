@@ -0,0 +1,43 @@
+//! Named meteorological scenarios shared by the integration tests.
+//!
+//! Each scenario is a physically consistent set of [`MeteoInputs`] (the dew point never exceeds the
+//! air temperature) for a recognisable climate, chosen so every index is exercised near the edges
+//! of the conditions it is used for.
+
+#![allow(dead_code)]
+
+use thermofeel_rs::MeteoInputs;
+use thermofeel_rs::constants::ZERO_CELSIUS_K;
+
+const fn inputs(t2_c: f64, td_c: f64, va: f64, mrt_c: f64) -> MeteoInputs {
+    MeteoInputs {
+        t2_k: t2_c + ZERO_CELSIUS_K,
+        td_k: td_c + ZERO_CELSIUS_K,
+        va,
+        mrt: mrt_c + ZERO_CELSIUS_K,
+    }
+}
+
+/// A sunny, humid monsoon afternoon in South or Southeast Asia: 33°C with a 27°C dew point (about
+/// 70% relative humidity), a light 2 m/s breeze and a mean radiant temperature of 55°C.
+pub const HOT_HUMID_TROPICAL: MeteoInputs = inputs(33.0, 27.0, 2.0, 55.0);
+
+/// A desert afternoon in summer: 45°C with a 5°C dew point (about 10% relative humidity), a 4 m/s
+/// wind and strong sun giving a mean radiant temperature of 65°C.
+pub const HOT_DRY_DESERT: MeteoInputs = inputs(45.0, 5.0, 4.0, 65.0);
+
+/// An overcast spring day in north-western Europe: 18°C with a 10°C dew point (about 60% relative
+/// humidity), a 3 m/s wind and a mean radiant temperature of 22°C.
+pub const MILD_TEMPERATE: MeteoInputs = inputs(18.0, 10.0, 3.0, 22.0);
+
+/// A polar winter day: -25°C with a -28°C dew point, a 10 m/s wind and a mean radiant temperature
+/// of -30°C under a clear sky.
+pub const COLD_WINDY_ARCTIC: MeteoInputs = inputs(-25.0, -28.0, 10.0, -30.0);
+
+/// Every scenario, with its name.
+pub const SCENARIOS: [(&str, MeteoInputs); 4] = [
+    ("HOT_HUMID_TROPICAL", HOT_HUMID_TROPICAL),
+    ("HOT_DRY_DESERT", HOT_DRY_DESERT),
+    ("MILD_TEMPERATE", MILD_TEMPERATE),
+    ("COLD_WINDY_ARCTIC", COLD_WINDY_ARCTIC),
+];
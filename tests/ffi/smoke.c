@@ -0,0 +1,43 @@
+/*
+ * Smoke test for the C bindings.
+ *
+ * cargo rustc --lib --crate-type cdylib --features ffi
+ * cc tests/ffi/smoke.c -Iinclude -Ltarget/debug -lthermofeel_rs -lm -o target/smoke
+ * LD_LIBRARY_PATH=target/debug ./target/smoke
+ */
+#include <math.h>
+#include <stdio.h>
+
+#include "thermofeel.h"
+
+static int failures = 0;
+
+static void check(const char *name, int ok) {
+    if (!ok) {
+        fprintf(stderr, "FAILED: %s\n", name);
+        failures++;
+    }
+}
+
+int main(void) {
+    double out = 0.0;
+
+    check("utci status", tf_calculate_utci(309.0, 3.0, 310.0, NAN, 12.0, &out) == TF_OK);
+    check("utci value", fabs(out - 307.76473586) < 1e-5);
+
+    check("utci missing humidity", tf_calculate_utci(309.0, 3.0, 310.0, NAN, NAN, &out) == TF_MISSING_INPUT);
+    check("utci missing humidity writes NaN", isnan(out));
+
+    check("humidex status", tf_calculate_humidex(304.0, 300.0, &out) == TF_OK);
+    check("humidex value", fabs(out - 318.4601286141123) < 1e-6);
+
+    check("heat index not applicable", tf_calculate_heat_index_simplified(283.15, 80.0, &out) == TF_NOT_APPLICABLE);
+    check("heat index not applicable writes NaN", isnan(out));
+
+    check("null out pointer", tf_calculate_wbgt(300.0, 310.0, 20.0, 290.0, NULL) == TF_NULL_POINTER);
+
+    if (failures == 0) {
+        printf("ffi smoke test passed\n");
+    }
+    return failures;
+}
@@ -184,7 +184,7 @@ fn test_utci() -> io::Result<()> {
 
     for (i, case) in test_cases.iter().enumerate() {
         let rh_pc = calculate_relative_humidity_percent(case.t2m, case.td);
-        let ehpa = calculate_saturation_vapour_pressure(case.t2m) * rh_pc / 100.0;
+        let ehpa = relative_humidity_to_vapour_pressure(case.t2m, rh_pc);
         let utci = calculate_utci(case.t2m, case.va, case.mrt, None, Some(ehpa));
         assert_almost_equal(expected_utci[i], utci, 6, "test_utci", i);
     }
@@ -0,0 +1,278 @@
+use thermofeel_rs::*;
+
+#[test]
+fn test_utci_slice_matches_scalar() {
+    let t2_k = [300.0, 305.0, 280.0];
+    let va = [1.0, 3.0, 8.0];
+    let mrt = [310.0, 320.0, 275.0];
+    let td_k = [290.0, 295.0, 270.0];
+
+    let utci = calculate_utci_slice(&t2_k, &va, &mrt, &td_k);
+    for i in 0..t2_k.len() {
+        let expected = calculate_utci(t2_k[i], va[i], mrt[i], Some(td_k[i]), None);
+        assert_eq!(utci[i], expected);
+    }
+}
+
+#[test]
+fn test_utci_slice_propagates_nan() {
+    let nan = f64::NAN;
+    let t2_k = [nan, 300.0, 300.0, 300.0, 300.0];
+    let va = [3.0, nan, 3.0, 3.0, 3.0];
+    let mrt = [310.0, 310.0, nan, 310.0, 310.0];
+    let td_k = [290.0, 290.0, 290.0, nan, 290.0];
+
+    let utci = calculate_utci_slice(&t2_k, &va, &mrt, &td_k);
+    assert!(utci[..4].iter().all(|u| u.is_nan()));
+    assert!(utci[4].is_finite());
+}
+
+#[test]
+fn test_utci_slice_masked() {
+    let t2_k = [300.0, 305.0, 280.0];
+    let va = [1.0, 3.0, 8.0];
+    let mrt = [310.0, 320.0, 275.0];
+    let td_k = [290.0, 295.0, 270.0];
+    let mask = [false, true, false];
+
+    let utci = calculate_utci_slice_masked(&t2_k, &va, &mrt, &td_k, &mask);
+    let unmasked = calculate_utci_slice(&t2_k, &va, &mrt, &td_k);
+    assert_eq!(utci[0], unmasked[0]);
+    assert!(utci[1].is_nan());
+    assert_eq!(utci[2], unmasked[2]);
+}
+
+#[test]
+#[should_panic(expected = "`va` has length 2 but `t2_k` has length 3")]
+fn test_utci_slice_rejects_mismatched_lengths() {
+    calculate_utci_slice(&[300.0; 3], &[3.0; 2], &[310.0; 3], &[290.0; 3]);
+}
+
+#[test]
+fn test_utci_slice_checked() {
+    let t2_k = [300.0, 305.0, 280.0, 290.0, 295.0];
+    let va = [1.0, 3.0, 8.0, 2.0];
+    let mrt = [310.0; 5];
+    let td_k = [285.0; 5];
+
+    assert_eq!(
+        calculate_utci_slice_checked(&t2_k, &va, &mrt, &td_k),
+        Err(ThermofeelError::MismatchedSliceLengths {
+            expected: 5,
+            got: 4
+        })
+    );
+    assert_eq!(
+        calculate_utci_slice_checked(&t2_k, &[2.0; 5], &mrt[..3], &td_k),
+        Err(ThermofeelError::MismatchedSliceLengths {
+            expected: 5,
+            got: 3
+        })
+    );
+
+    let va = [1.0, 3.0, 8.0, 2.0, 0.5];
+    assert_eq!(
+        calculate_utci_slice_checked(&t2_k, &va, &mrt, &td_k),
+        Ok(calculate_utci_slice(&t2_k, &va, &mrt, &td_k))
+    );
+}
+
+#[test]
+fn test_nan_windspeed_is_not_calm() {
+    assert!(scale_windspeed(f64::NAN, 1.1).is_nan());
+    assert!(calculate_bgt(300.0, 310.0, f64::NAN).is_nan());
+    assert!(calculate_wind_chill(270.0, f64::NAN).is_nan());
+}
+
+#[test]
+fn test_degree_days_propagate_nan() {
+    let daily_mean_k = [290.0, f64::NAN, 295.0];
+    assert!(calculate_cooling_degree_days(&daily_mean_k, 291.15).is_nan());
+    assert!(calculate_heating_degree_days(&daily_mean_k, 291.15).is_nan());
+}
+
+#[test]
+fn test_map_utci_matches_slice() {
+    let t2_k = [300.0, 305.0, 280.0, f64::NAN];
+    let va = [1.0, 3.0, 8.0, 2.0];
+    let mrt = [310.0, 320.0, 275.0, 300.0];
+    let td_k = [290.0, 295.0, 270.0, 285.0];
+
+    let inputs = (0..t2_k.len()).map(|i| (t2_k[i], va[i], mrt[i], td_k[i]));
+    let streamed: Vec<f64> = inputs.map_utci().collect();
+    let sliced = calculate_utci_slice(&t2_k, &va, &mrt, &td_k);
+
+    assert_eq!(streamed.len(), sliced.len());
+    assert_eq!(streamed[..3], sliced[..3]);
+    assert!(streamed[3].is_nan());
+}
+
+#[test]
+fn test_meteo_columns_match_scalar() {
+    let columns = MeteoColumns {
+        t2_k: vec![300.0, 305.0, 280.0],
+        td_k: vec![290.0, 295.0, 270.0],
+        va: vec![1.0, 3.0, 8.0],
+        mrt: vec![310.0, 320.0, 275.0],
+        radiation: Some(RadiationColumns {
+            ssrd: vec![600.0, 800.0, 100.0],
+            ssr: vec![500.0, 650.0, 80.0],
+            dsrp: vec![200.0, 400.0, 10.0],
+            strd: vec![350.0, 380.0, 250.0],
+            fdir: vec![150.0, 300.0, 5.0],
+            strr: vec![-80.0, -100.0, -40.0],
+            cossza: vec![0.5, 0.8, 0.2],
+        }),
+    };
+
+    let utci = columns.compute_utci();
+    let wbgt = columns.compute_wbgt();
+    let humidex = columns.compute_humidex();
+    let wbt = columns.compute_wbt();
+    let mrt = columns.compute_mean_radiant_temperature().unwrap();
+    let r = columns.radiation.as_ref().unwrap();
+
+    for i in 0..columns.len() {
+        let (t2_k, td_k, va, mrt_i) = (
+            columns.t2_k[i],
+            columns.td_k[i],
+            columns.va[i],
+            columns.mrt[i],
+        );
+        let rh = calculate_relative_humidity_percent(t2_k, td_k);
+
+        assert_eq!(utci[i], calculate_utci(t2_k, va, mrt_i, Some(td_k), None));
+        assert_eq!(wbgt[i], calculate_wbgt(t2_k, mrt_i, va, td_k));
+        assert_eq!(humidex[i], calculate_humidex(t2_k, td_k));
+        assert_eq!(wbt[i], calculate_wbt(t2_k, rh));
+        assert_eq!(
+            mrt[i],
+            calculate_mean_radiant_temperature(
+                r.ssrd[i],
+                r.ssr[i],
+                r.dsrp[i],
+                r.strd[i],
+                r.fdir[i],
+                r.strr[i],
+                r.cossza[i]
+            )
+        );
+    }
+
+    let without_radiation = MeteoColumns {
+        radiation: None,
+        ..columns
+    };
+    assert_eq!(without_radiation.compute_mean_radiant_temperature(), None);
+}
+
+#[test]
+#[should_panic(expected = "`va` has length 1 but `t2_k` has length 2")]
+fn test_meteo_columns_reject_mismatched_lengths() {
+    let columns = MeteoColumns {
+        t2_k: vec![300.0, 305.0],
+        td_k: vec![290.0, 295.0],
+        va: vec![1.0],
+        mrt: vec![310.0, 320.0],
+        radiation: None,
+    };
+    columns.compute_wbgt();
+}
+
+fn pipeline_columns() -> MeteoColumns {
+    MeteoColumns {
+        t2_k: vec![300.0, 305.0, 280.0],
+        td_k: vec![290.0, 295.0, 270.0],
+        va: vec![1.0, 3.0, 8.0],
+        mrt: vec![310.0, 320.0, 275.0],
+        radiation: None,
+    }
+}
+
+#[test]
+fn test_pipeline_matches_meteo_columns() {
+    let columns = pipeline_columns();
+    let results = Pipeline::new()
+        .with_utci()
+        .with_wbgt()
+        .with_humidex()
+        .with_wbt()
+        .run(&columns);
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results["utci"], columns.compute_utci());
+    assert_eq!(results["wbgt"], columns.compute_wbgt());
+    assert_eq!(results["humidex"], columns.compute_humidex());
+    assert_eq!(results["wbt"], columns.compute_wbt());
+}
+
+#[test]
+fn test_pipeline_computes_shared_relative_humidity_once() {
+    let columns = pipeline_columns();
+    let intermediates = Intermediates::new(&columns);
+
+    let results = Pipeline::new()
+        .with_wbgt()
+        .with_wbt()
+        .run_with(&intermediates);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(intermediates.evaluations(), 1);
+
+    let intermediates = Intermediates::new(&columns);
+    Pipeline::new().with_humidex().run_with(&intermediates);
+    assert_eq!(intermediates.evaluations(), 0);
+}
+
+#[test]
+fn test_unit_conversion_slices() {
+    let celsius = [-40.0, 0.0, 36.6, f64::NAN];
+
+    let kelvin = celsius_to_kelvin_slice(&celsius);
+    for (k, c) in kelvin.iter().zip(&celsius).take(3) {
+        assert_eq!(*k, celsius_to_kelvin(*c));
+    }
+    assert!(kelvin[3].is_nan());
+
+    let mut values = kelvin.clone();
+    kelvin_to_fahrenheit_inplace(&mut values);
+    for (f, k) in values.iter().zip(&kelvin).take(3) {
+        assert_eq!(*f, kelvin_to_fahrenheit(*k));
+    }
+    assert!((values[0] + 40.0).abs() < 1e-9);
+    assert!(values[3].is_nan());
+
+    fahrenheit_to_celsius_inplace(&mut values);
+    for (c, expected) in values.iter().zip(&celsius).take(3) {
+        assert!((c - expected).abs() < 1e-9);
+    }
+
+    assert!(rankine_to_kelvin_slice(&[]).is_empty());
+}
+
+#[test]
+fn test_humidity_slices() {
+    let t2_k = [300.0, 285.0, 260.0, f64::NAN];
+    let td_k = [295.0, 280.0, 255.0, 250.0];
+
+    let rh = calculate_relative_humidity_percent_slice(&t2_k, &td_k);
+    for i in 0..3 {
+        assert_eq!(rh[i], calculate_relative_humidity_percent(t2_k[i], td_k[i]));
+    }
+    assert!(rh[3].is_nan());
+
+    let td = calculate_dew_point_from_relative_humidity_slice(&rh, &t2_k);
+    for i in 0..3 {
+        assert_eq!(
+            td[i],
+            calculate_dew_point_from_relative_humidity(rh[i], t2_k[i])
+        );
+    }
+    assert!(td[3].is_nan());
+}
+
+#[test]
+#[should_panic(expected = "`rh` has length 2 but `t2_k` has length 3")]
+fn test_dew_point_slice_rejects_mismatched_lengths() {
+    calculate_dew_point_from_relative_humidity_slice(&[50.0; 2], &[300.0; 3]);
+}
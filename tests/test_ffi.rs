@@ -0,0 +1,37 @@
+#![cfg(feature = "ffi")]
+
+use approx::assert_relative_eq;
+use thermofeel_rs::celsius_to_kelvin;
+use thermofeel_rs::ffi::*;
+
+#[test]
+fn test_ffi_utci() {
+    let mut out = 0.0;
+    let status = unsafe { tf_calculate_utci(309.0, 3.0, 310.0, f64::NAN, 12.0, &mut out) };
+    assert_eq!(status, TF_OK);
+    assert_relative_eq!(out, 307.76473586, epsilon = 1e-5);
+
+    let status = unsafe { tf_calculate_utci(309.0, 3.0, 310.0, f64::NAN, f64::NAN, &mut out) };
+    assert_eq!(status, TF_MISSING_INPUT);
+    assert!(out.is_nan());
+}
+
+#[test]
+fn test_ffi_heat_index_not_applicable() {
+    let mut out = 0.0;
+    let t2_k = celsius_to_kelvin(10.0);
+    let status = unsafe { tf_calculate_heat_index_simplified(t2_k, 80.0, &mut out) };
+    assert_eq!(status, TF_NOT_APPLICABLE);
+    assert!(out.is_nan());
+
+    let t2_k = celsius_to_kelvin(21.0);
+    let status = unsafe { tf_calculate_heat_index_simplified(t2_k, 80.0, &mut out) };
+    assert_eq!(status, TF_OK);
+    assert_relative_eq!(out, 294.68866082, epsilon = 1e-6);
+}
+
+#[test]
+fn test_ffi_null_out_pointer() {
+    let status = unsafe { tf_calculate_wbgt(300.0, 310.0, 20.0, 290.0, std::ptr::null_mut()) };
+    assert_eq!(status, TF_NULL_POINTER);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{arr0, arr1, arr2};
+use thermofeel_rs::*;
+
+#[test]
+fn test_utci_array_broadcasts_scalar() {
+    let t2_k = arr2(&[[300.0, 305.0, 310.0], [280.0, 285.0, 290.0]]);
+    let mrt = arr2(&[[310.0, 315.0, 320.0], [275.0, 280.0, 285.0]]);
+    let td_k = arr1(&[275.0, 280.0, 285.0]);
+    let va = arr0(3.0);
+
+    let utci = calculate_utci_array(&t2_k.view(), &va.view(), &mrt.view(), &td_k.view());
+    assert_eq!(utci.shape(), &[2, 3]);
+
+    for ((i, j), &value) in utci.indexed_iter() {
+        let expected = calculate_utci(t2_k[[i, j]], 3.0, mrt[[i, j]], Some(td_k[j]), None);
+        assert_eq!(value, expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot be broadcast")]
+fn test_utci_array_rejects_incompatible_shapes() {
+    let t2_k = arr2(&[[300.0, 305.0, 310.0], [280.0, 285.0, 290.0]]);
+    let td_k = arr1(&[275.0, 280.0]);
+    calculate_utci_array(&t2_k.view(), &arr0(3.0).view(), &t2_k.view(), &td_k.view());
+}
+
+#[test]
+fn test_utci_array_propagates_nan() {
+    let t2_k = arr1(&[300.0, f64::NAN, 310.0]);
+    let td_k = arr1(&[290.0, 290.0, f64::NAN]);
+    let utci = calculate_utci_array(
+        &t2_k.view(),
+        &arr0(3.0).view(),
+        &arr0(310.0).view(),
+        &td_k.view(),
+    );
+    assert!(utci[0].is_finite());
+    assert!(utci[1].is_nan());
+    assert!(utci[2].is_nan());
+}
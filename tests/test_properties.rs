@@ -0,0 +1,85 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use proptest::prelude::*;
+use thermofeel_rs::*;
+
+proptest! {
+    /// Heat index does not fall as temperature rises, at humidities where the regression applies.
+    #[test]
+    fn heat_index_non_decreasing_in_temperature(
+        t2_c in 27.0..45.0_f64,
+        dt in 0.0..5.0_f64,
+        rh in 40.0..100.0_f64,
+    ) {
+        let lo = calculate_heat_index_simplified(celsius_to_kelvin(t2_c), rh).unwrap();
+        let hi = calculate_heat_index_simplified(celsius_to_kelvin(t2_c + dt), rh).unwrap();
+        prop_assert!(hi >= lo);
+    }
+
+    /// Heat index does not fall as humidity rises.
+    #[test]
+    fn heat_index_non_decreasing_in_humidity(
+        t2_c in 27.0..45.0_f64,
+        rh in 40.0..95.0_f64,
+        drh in 0.0..5.0_f64,
+    ) {
+        let t2_k = celsius_to_kelvin(t2_c);
+        let lo = calculate_heat_index_simplified(t2_k, rh).unwrap();
+        let hi = calculate_heat_index_simplified(t2_k, rh + drh).unwrap();
+        prop_assert!(hi >= lo);
+    }
+
+    /// Wind chill does not rise as the wind picks up, over the range it is defined for.
+    #[test]
+    fn wind_chill_non_increasing_in_wind_speed(
+        t2_c in -50.0..10.0_f64,
+        va in (5.0 / 3.6)..(75.0 / 3.6),
+        dva in 0.0..(5.0 / 3.6),
+    ) {
+        let t2_k = celsius_to_kelvin(t2_c);
+        let calm = calculate_wind_chill(t2_k, va);
+        let windy = calculate_wind_chill(t2_k, va + dva);
+        prop_assert!(windy <= calm);
+    }
+
+    /// Humidex does not fall as the dew point rises towards the air temperature.
+    #[test]
+    fn humidex_non_decreasing_in_dew_point(
+        t2_c in 0.0..45.0_f64,
+        depression in 0.0..30.0_f64,
+        dtd in 0.0..5.0_f64,
+    ) {
+        let t2_k = celsius_to_kelvin(t2_c);
+        let td_k = t2_k - depression - dtd;
+        let lo = calculate_humidex(t2_k, td_k);
+        let hi = calculate_humidex(t2_k, td_k + dtd);
+        prop_assert!(hi >= lo);
+    }
+
+    /// Saturation vapour pressure rises strictly with temperature.
+    #[test]
+    fn saturation_vapour_pressure_increasing_in_temperature(
+        t2_k in 173.15..373.15_f64,
+        dt in 0.01..5.0_f64,
+    ) {
+        let lo = calculate_saturation_vapour_pressure(t2_k);
+        let hi = calculate_saturation_vapour_pressure(t2_k + dt);
+        prop_assert!(hi > lo);
+    }
+
+    /// Wet bulb temperature does not fall as humidity rises, and never exceeds the air temperature
+    /// by more than the error of the Stull (2011) fit. The fit is not valid for cold, dry air, so
+    /// that corner is excluded.
+    #[test]
+    fn wet_bulb_non_decreasing_in_humidity(
+        t2_c in 0.0..50.0_f64,
+        rh in 20.0..94.0_f64,
+        drh in 0.0..5.0_f64,
+    ) {
+        let t2_k = celsius_to_kelvin(t2_c);
+        let lo = calculate_wbt(t2_k, rh);
+        let hi = calculate_wbt(t2_k, rh + drh);
+        prop_assert!(hi >= lo);
+        prop_assert!(hi <= t2_k + 1.0);
+    }
+}
@@ -1,5 +1,8 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
+    use crate::common::*;
     use approx::assert_relative_eq;
     use thermofeel_rs::*;
 
@@ -18,6 +21,35 @@ mod tests {
         assert_relative_eq!(svp, 31.699201897293, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_saturation_vapour_pressure_its90_range() {
+        // over water, from the IAPWS-95 tables in the CRC Handbook of Chemistry and Physics
+        let reference = [
+            (0.0, 6.1121),
+            (10.0, 12.282),
+            (20.0, 23.393),
+            (30.0, 42.470),
+            (40.0, 73.849),
+            (50.0, 123.52),
+        ];
+        for (t2_c, es) in reference {
+            let svp = calculate_saturation_vapour_pressure(celsius_to_kelvin(t2_c));
+            assert_relative_eq!(svp, es, max_relative = 1e-4);
+        }
+
+        // over supercooled water, from Murphy and Koop (2005)
+        let reference = [
+            (-10.0, 2.865),
+            (-20.0, 1.256),
+            (-30.0, 0.5103),
+            (-40.0, 0.1903),
+        ];
+        for (t2_c, es) in reference {
+            let svp = calculate_saturation_vapour_pressure(celsius_to_kelvin(t2_c));
+            assert_relative_eq!(svp, es, max_relative = 1e-2);
+        }
+    }
+
     #[test]
     fn test_saturation_vapour_pressure_multiphase() {
         let t2_k = celsius_to_kelvin(-25.0);
@@ -49,6 +81,19 @@ mod tests {
         assert_relative_eq!(td_k, 294.3484414118635, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_relative_humidity_dew_point_round_trip() {
+        for t2_c in (-40..=50).step_by(5) {
+            let t2_k = celsius_to_kelvin(t2_c as f64);
+            for rh in (5..=100).step_by(5) {
+                let rh = rh as f64;
+                let td_k = calculate_dew_point_from_relative_humidity(rh, t2_k);
+                let rh_round_trip = calculate_relative_humidity_percent(t2_k, td_k);
+                assert_relative_eq!(rh_round_trip, rh, epsilon = 0.6);
+            }
+        }
+    }
+
     #[test]
     fn test_mean_radiant_temperature() {
         let ssrd = 60000.0 / 3600.0;
@@ -82,6 +127,25 @@ mod tests {
         assert_relative_eq!(kelvin_to_celsius(utci_2), 18.93148565062157, epsilon = 1e-5);
     }
 
+    #[test]
+    fn test_utci_from_specific_humidity() {
+        let t2_k = 309.0;
+        let va = 3.0;
+        let mrt = 310.0;
+        let e_hpa = 12.0;
+        let pressure_hpa = 1000.0;
+        let q = 0.622 * e_hpa / (pressure_hpa - 0.378 * e_hpa);
+        assert_relative_eq!(
+            vapour_pressure_from_specific_humidity(q, pressure_hpa),
+            e_hpa,
+            epsilon = 1e-12
+        );
+
+        let utci_q = calculate_utci_from_specific_humidity(t2_k, va, mrt, q, pressure_hpa);
+        let utci_e = calculate_utci(t2_k, va, mrt, None, Some(e_hpa));
+        assert_relative_eq!(utci_q, utci_e, epsilon = 1e-9);
+    }
+
     #[test]
     fn test_wbgt_simple() {
         let t2_k = celsius_to_kelvin(30.0);
@@ -90,6 +154,35 @@ mod tests {
         assert_relative_eq!(wbgts, 307.39508355517813, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_wbgt_simple_at_pressure() {
+        let t2_k = celsius_to_kelvin(30.0);
+        let rh = 80.0;
+        let wbgts = calculate_wbgt_simple(t2_k, rh);
+        assert_eq!(calculate_wbgt_simple_at_pressure(t2_k, rh, 1013.25), wbgts);
+
+        // The wet bulb of drier air falls further at altitude
+        let wbgts_altitude = calculate_wbgt_simple_at_pressure(t2_k, rh, 700.0);
+        assert_relative_eq!(wbgts_altitude - wbgts, -0.1664, epsilon = 1e-3);
+        let dry_wbgts = calculate_wbgt_simple(t2_k, 50.0);
+        let dry_wbgts_altitude = calculate_wbgt_simple_at_pressure(t2_k, 50.0, 700.0);
+        assert_relative_eq!(dry_wbgts_altitude - dry_wbgts, -0.5652, epsilon = 1e-3);
+        assert_relative_eq!(
+            dry_wbgts_altitude - dry_wbgts,
+            0.7 * (calculate_wbt_method(t2_k, 50.0, 700.0, WetBulbMethod::DaviesJones)
+                - calculate_wbt_method(t2_k, 50.0, 1013.25, WetBulbMethod::DaviesJones)),
+            epsilon = 1e-12
+        );
+
+        // Saturated air has no psychrometric depression to change
+        let saturated = calculate_wbgt_simple(t2_k, 100.0);
+        assert_relative_eq!(
+            calculate_wbgt_simple_at_pressure(t2_k, 100.0, 500.0),
+            saturated,
+            epsilon = 0.02
+        );
+    }
+
     #[test]
     fn test_wbt() {
         let t2_k = celsius_to_kelvin(20.0);
@@ -115,6 +208,53 @@ mod tests {
         assert_relative_eq!(bgt[2], 298.70216299754475, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_negative_windspeed_is_calm() {
+        let t2_k = 300.0;
+        let td_k = 290.0;
+        let mrt = 310.0;
+        let rh = 60.0;
+
+        assert_eq!(scale_windspeed(-5.0, 1.1), 0.0);
+
+        let bgt = calculate_bgt(t2_k, mrt, -5.0);
+        assert!(bgt.is_finite());
+        assert_relative_eq!(bgt, mrt, epsilon = 1e-9);
+        assert_relative_eq!(bgt, calculate_bgt(t2_k, mrt, 0.0), epsilon = 1e-9);
+
+        let wbgt = calculate_wbgt(t2_k, mrt, -5.0, td_k);
+        assert!(wbgt.is_finite());
+        assert_relative_eq!(wbgt, calculate_wbgt(t2_k, mrt, 0.0, td_k), epsilon = 1e-9);
+
+        let net = calculate_normal_effective_temperature(t2_k, -5.0, rh);
+        assert!(net.is_finite());
+        assert_relative_eq!(
+            net,
+            calculate_normal_effective_temperature(t2_k, 0.0, rh),
+            epsilon = 1e-9
+        );
+
+        let wc = calculate_wind_chill(270.0, -5.0);
+        assert!(wc.is_finite());
+        assert_relative_eq!(wc, calculate_wind_chill(270.0, 0.0), epsilon = 1e-9);
+
+        assert_eq!(
+            calculate_utci(t2_k, -5.0, mrt, Some(td_k), None),
+            calculate_utci(t2_k, 0.0, mrt, Some(td_k), None)
+        );
+        assert!(calculate_utci(t2_k, f64::NAN, mrt, Some(td_k), None).is_nan());
+        assert!(calculate_utci_checked(t2_k, -5.0, mrt, Some(td_k), None).is_err());
+
+        assert_eq!(
+            calculate_apparent_temperature(t2_k, -5.0, rh),
+            calculate_apparent_temperature(t2_k, 0.0, rh)
+        );
+        assert_eq!(
+            calculate_apparent_temperature_with_radiation(t2_k, -5.0, rh, 100.0),
+            calculate_apparent_temperature_with_radiation(t2_k, 0.0, rh, 100.0)
+        );
+    }
+
     #[test]
     fn test_wbgt() {
         let t2_k = 300.0;
@@ -180,6 +320,20 @@ mod tests {
         assert_relative_eq!(wc_c_wiki_2, -32.56804448, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_wind_chill_siple() {
+        // the pre-2001 NWS chart gives -39°F at 0°F with a 20 mph wind
+        let t2_k = fahrenheit_to_kelvin(0.0);
+        let va = 20.0 * 0.44704;
+        let wc_k = calculate_wind_chill_siple(t2_k, va);
+        assert_relative_eq!(kelvin_to_fahrenheit(wc_k), -39.0, epsilon = 0.5);
+
+        // at 1.8 m/s the equivalent temperature is the air temperature
+        let t2_k = celsius_to_kelvin(-10.0);
+        let wc_k = calculate_wind_chill_siple(t2_k, 1.79);
+        assert_relative_eq!(wc_k, t2_k, epsilon = 0.05);
+    }
+
     #[test]
     fn test_heat_index_simplified() {
         let t2_k = celsius_to_kelvin(21.0);
@@ -194,5 +348,2032 @@ mod tests {
         let td_k = 290.0;
         let hia = calculate_heat_index_adjusted(t2_k, td_k).unwrap();
         assert_relative_eq!(hia, 295.15355699, epsilon = 1e-6);
+
+        // Dry heat takes the NOAA low humidity adjustment
+        // ((13 - RH) / 4) sqrt((17 - |T - 95|) / 17), here at about 11% humidity
+        for (t2_f, td_f, expected_f) in [(85.0, 25.0, 81.5683), (105.0, 40.0, 99.6311)] {
+            let hia = calculate_heat_index_adjusted(
+                fahrenheit_to_kelvin(t2_f),
+                fahrenheit_to_kelvin(td_f),
+            );
+            assert_relative_eq!(
+                kelvin_to_fahrenheit(hia.unwrap()),
+                expected_f,
+                epsilon = 1e-3
+            );
+        }
+    }
+
+    #[test]
+    fn test_degree_days() {
+        let base_k = celsius_to_kelvin(18.0);
+        let daily_mean_k = [15.0, 18.0, 21.5, 25.0, 10.0].map(celsius_to_kelvin);
+
+        let cdd = calculate_cooling_degree_days(&daily_mean_k, base_k);
+        assert_relative_eq!(cdd, 10.5, epsilon = 1e-9);
+
+        let hdd = calculate_heating_degree_days(&daily_mean_k, base_k);
+        assert_relative_eq!(hdd, 11.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_calculate_all() {
+        // hot conditions: heat index applies, wind chill does not
+        let t2_k = celsius_to_kelvin(32.0);
+        let td_k = celsius_to_kelvin(24.0);
+        let va = 3.0;
+        let mrt = celsius_to_kelvin(45.0);
+        let rh = calculate_relative_humidity_percent(t2_k, td_k);
+
+        let all = calculate_all(t2_k, td_k, va, mrt);
+        assert_eq!(all.utci, calculate_utci(t2_k, va, mrt, Some(td_k), None));
+        assert_eq!(all.wbgt, calculate_wbgt(t2_k, mrt, va, td_k));
+        assert_eq!(all.humidex, calculate_humidex(t2_k, td_k));
+        assert_eq!(
+            all.apparent_temperature,
+            calculate_apparent_temperature(t2_k, va, rh)
+        );
+        assert_eq!(all.heat_index, calculate_heat_index_simplified(t2_k, rh));
+        assert!(all.heat_index.is_some());
+        assert_eq!(all.wind_chill, None);
+        assert_eq!(all.wbt, calculate_wbt(t2_k, rh));
+        assert_eq!(
+            all.net,
+            calculate_normal_effective_temperature(t2_k, va, rh)
+        );
+
+        // cold conditions: wind chill applies, heat index does not
+        let t2_k = celsius_to_kelvin(-10.0);
+        let td_k = celsius_to_kelvin(-15.0);
+        let va = 8.0;
+        let all = calculate_all(t2_k, td_k, va, t2_k);
+        assert_eq!(all.heat_index, None);
+        assert_eq!(all.wind_chill, Some(calculate_wind_chill(t2_k, va)));
+    }
+
+    #[test]
+    fn test_celsius_wrappers() {
+        let conditions = [(30.0, 45.0, 2.0, 22.0), (5.0, 0.0, 8.0, -2.0)];
+
+        for (t2_c, mrt_c, va, td_c) in conditions {
+            let t2_k = celsius_to_kelvin(t2_c);
+            let mrt_k = celsius_to_kelvin(mrt_c);
+            let td_k = celsius_to_kelvin(td_c);
+            let rh = calculate_relative_humidity_percent(t2_k, td_k);
+
+            assert_relative_eq!(
+                celsius::calculate_relative_humidity_percent(t2_c, td_c),
+                rh,
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                celsius::calculate_utci(t2_c, va, mrt_c, Some(td_c), None),
+                kelvin_to_celsius(calculate_utci(t2_k, va, mrt_k, Some(td_k), None)),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                celsius::calculate_wbgt(t2_c, mrt_c, va, td_c),
+                kelvin_to_celsius(calculate_wbgt(t2_k, mrt_k, va, td_k)),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                celsius::calculate_humidex(t2_c, td_c),
+                kelvin_to_celsius(calculate_humidex(t2_k, td_k)),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                celsius::calculate_apparent_temperature(t2_c, va, rh),
+                kelvin_to_celsius(calculate_apparent_temperature(t2_k, va, rh)),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                celsius::calculate_wind_chill(t2_c, va),
+                kelvin_to_celsius(calculate_wind_chill(t2_k, va)),
+                epsilon = 1e-9
+            );
+            assert_eq!(
+                celsius::calculate_heat_index_simplified(t2_c, rh).is_some(),
+                calculate_heat_index_simplified(t2_k, rh).is_some()
+            );
+        }
+    }
+
+    #[test]
+    fn test_water_vapour_pressure_kpa() {
+        // Saturated air at 300 K, where Hardy (1998) gives 35.37 hPa
+        let wvp = calculate_water_vapour_pressure_kpa(300.0, 300.0);
+        assert_relative_eq!(wvp, 3.537, epsilon = 1e-3);
+
+        let eh_pa = wvp * 10.0;
+        assert_eq!(
+            calculate_utci(300.0, 2.0, 310.0, Some(300.0), None),
+            calculate_utci(300.0, 2.0, 310.0, None, Some(eh_pa))
+        );
+    }
+
+    #[test]
+    fn test_utci_checked() {
+        assert_eq!(
+            calculate_utci_checked(300.0, 20.0, 310.0, Some(290.0), None),
+            Err(ThermofeelError::OutOfValidRange {
+                name: "va",
+                value: 20.0,
+                min: 0.5,
+                max: 17.0
+            })
+        );
+        assert_eq!(
+            calculate_utci_checked(300.0, 3.0, 310.0, None, None),
+            Err(ThermofeelError::MissingHumidityInput)
+        );
+        assert!(matches!(
+            calculate_utci_checked(330.0, 3.0, 330.0, Some(290.0), None),
+            Err(ThermofeelError::OutOfValidRange { name: "t2_c", .. })
+        ));
+        assert!(matches!(
+            calculate_utci_checked(300.0, 3.0, 260.0, Some(290.0), None),
+            Err(ThermofeelError::OutOfValidRange {
+                name: "mrt - t2_k",
+                ..
+            })
+        ));
+        assert!(matches!(
+            calculate_utci_checked(300.0, 3.0, 310.0, None, Some(60.0)),
+            Err(ThermofeelError::OutOfValidRange { name: "wvp", .. })
+        ));
+
+        let checked = calculate_utci_checked(300.0, 3.0, 310.0, Some(290.0), None).unwrap();
+        let unchecked = calculate_utci(300.0, 3.0, 310.0, Some(290.0), None);
+        assert_relative_eq!(checked, unchecked, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_mrt_from_globe() {
+        for (t2_k, bgt_k, va) in [
+            (300.0, 310.0, 2.0),
+            (290.0, 295.0, 6.0),
+            (280.0, 285.0, 0.0),
+        ] {
+            assert_eq!(
+                calculate_mrt_from_bgt(t2_k, bgt_k, va),
+                calculate_mrt_from_globe(t2_k, bgt_k, va, 0.15, 0.95)
+            );
+        }
+
+        // A smaller globe couples more strongly to the air, so the same reading implies a
+        // hotter radiant environment
+        let standard = calculate_mrt_from_globe(300.0, 310.0, 2.0, 0.15, 0.95);
+        let small = calculate_mrt_from_globe(300.0, 310.0, 2.0, 0.05, 0.95);
+        assert!(small > standard);
+    }
+
+    #[test]
+    fn test_apparent_temperature_mode() {
+        let (t2_k, va, rh) = (303.15, 2.0, 50.0);
+
+        // q is ignored in the shade
+        assert_eq!(
+            calculate_apparent_temperature_mode(
+                t2_k,
+                va,
+                rh,
+                500.0,
+                ApparentTemperatureMode::Shade
+            ),
+            calculate_apparent_temperature(t2_k, va, rh)
+        );
+
+        let sun =
+            calculate_apparent_temperature_mode(t2_k, va, rh, 200.0, ApparentTemperatureMode::Sun);
+        assert_eq!(
+            sun,
+            calculate_apparent_temperature_with_radiation(t2_k, va, rh, 200.0)
+        );
+
+        // 30°C + 0.348 * 21.2 hPa - 0.7 * 2 + 0.7 * 200 / 12 - 4.25
+        assert_relative_eq!(kelvin_to_celsius(sun), 43.4, epsilon = 0.1);
+        assert!(sun > calculate_apparent_temperature(t2_k, va, rh));
+    }
+
+    #[test]
+    fn test_net_radiation_absorbed() {
+        let (t2_k, va, rh) = (303.15, 2.0, 50.0);
+        let shade = calculate_apparent_temperature(t2_k, va, rh);
+
+        // Midday sun, 800 W m-2 of which 600 W m-2 direct, over grass and with a net longwave loss
+        // of 100 W m-2
+        let (ssrd, ssr, fdir, strd, strr, cossza) = (800.0, 640.0, 600.0, 350.0, -100.0, 0.9);
+        let dsrp = approximate_dsrp(fdir, cossza).unwrap();
+        let day = calculate_net_radiation_absorbed(ssrd, ssr, dsrp, strd, fdir, strr, cossza, 0.3);
+
+        // The absorbed part is what the mean radiant temperature balances
+        let mrt = calculate_mean_radiant_temperature(ssrd, ssr, dsrp, strd, fdir, strr, cossza);
+        let emitted = 0.97 * (strd - strr);
+        assert_relative_eq!(
+            day,
+            0.97 * constants::STEFAN_BOLTZMANN * mrt.powi(4) - emitted,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(day, 152.07, epsilon = 0.01);
+        assert!(day < 0.7 * ssrd);
+        assert!(calculate_apparent_temperature_with_radiation(t2_k, va, rh, day) > shade);
+
+        // Clear night, no sun
+        let night = calculate_net_radiation_absorbed(0.0, 0.0, 0.0, 300.0, 0.0, -80.0, 0.0, 0.3);
+        assert_relative_eq!(night, 0.485 * -80.0, epsilon = 1e-9);
+        assert!(
+            calculate_apparent_temperature_with_radiation(t2_k, va, rh, night)
+                < calculate_apparent_temperature_with_radiation(t2_k, va, rh, day)
+        );
+    }
+
+    #[test]
+    fn test_classify_apparent_temperature() {
+        use ApparentTemperatureCategory::*;
+
+        let boundaries = [
+            (-0.01, VeryCold),
+            (0.0, Cold),
+            (9.99, Cold),
+            (10.0, Cool),
+            (18.0, Comfortable),
+            (25.99, Comfortable),
+            (26.0, Warm),
+            (32.0, Hot),
+            (39.99, Hot),
+            (40.0, Extreme),
+        ];
+
+        for (at_c, expected) in boundaries {
+            assert_eq!(
+                classify_apparent_temperature(celsius_to_kelvin(at_c)),
+                Some(expected),
+                "{at_c}°C"
+            );
+        }
+
+        let at = calculate_apparent_temperature(celsius_to_kelvin(22.0), 1.0, 50.0);
+        assert_eq!(classify_apparent_temperature(at), Some(Comfortable));
+        assert_eq!(classify_apparent_temperature(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_latent_heat_vaporization() {
+        assert_relative_eq!(
+            calculate_latent_heat_vaporization(273.15),
+            2.501e6,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            calculate_latent_heat_vaporization(celsius_to_kelvin(20.0)),
+            2.45378e6,
+            epsilon = 1.0
+        );
+    }
+
+    #[test]
+    fn test_vapour_pressure_deficit() {
+        let t2_k = celsius_to_kelvin(25.0);
+        assert_eq!(calculate_vapour_pressure_deficit(t2_k, 100.0), 0.0);
+
+        let vpd = calculate_vapour_pressure_deficit(t2_k, 60.0);
+        assert!(vpd > 0.0);
+        assert_relative_eq!(
+            vpd,
+            0.4 * calculate_saturation_vapour_pressure(t2_k),
+            epsilon = 1e-9
+        );
+        assert!(calculate_vapour_pressure_deficit(t2_k, 30.0) > vpd);
+    }
+
+    #[test]
+    fn test_constants() {
+        use thermofeel_rs::constants::*;
+
+        assert_eq!(STEFAN_BOLTZMANN, 5.67e-8);
+        assert_eq!(ZERO_CELSIUS_K, 273.15);
+        assert_eq!(TRIPLE_POINT_K, 273.16);
+        assert_eq!(MOLAR_MASS_RATIO, 0.622);
+        assert_eq!(LATENT_HEAT_OVER_VAPOUR_GAS_CONSTANT_K, 5417.7530);
+        assert_eq!(STANDARD_PRESSURE_HPA, 1013.25);
+
+        assert_eq!(celsius_to_kelvin(0.0), ZERO_CELSIUS_K);
+    }
+
+    #[test]
+    fn test_bgt_near_calm() {
+        // The closed-form quartic solution returned NaN here, and values above the mean radiant
+        // temperature for slightly stronger winds
+        for va in [1e-15, 1e-12, 1e-10] {
+            let bgt = calculate_bgt(300.0, 330.0, va);
+            assert!((300.0..=330.0).contains(&bgt), "va = {va}: {bgt}");
+            assert_relative_eq!(bgt, 330.0, epsilon = 0.01);
+        }
+
+        // Unchanged away from calm
+        let bgt = calculate_bgt(300.0, 330.0, 1e-3);
+        assert_relative_eq!(bgt, 329.36266501495953, epsilon = 1e-9);
+
+        assert!(calculate_bgt(f64::NAN, 330.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_feels_like() {
+        let windy = 10.0 / 3.6;
+        let still = 4.0 / 3.6;
+
+        // Wind chill at or below 10°C in a wind above 4.8 km/h
+        let t2_k = celsius_to_kelvin(10.0);
+        assert_eq!(
+            calculate_feels_like(t2_k, windy, 50.0),
+            calculate_wind_chill(t2_k, windy)
+        );
+        assert_eq!(calculate_feels_like(t2_k, still, 50.0), t2_k);
+        let t2_k = celsius_to_kelvin(10.5);
+        assert_eq!(calculate_feels_like(t2_k, windy, 50.0), t2_k);
+
+        // Heat index above 27°C
+        let t2_k = celsius_to_kelvin(27.0);
+        assert_eq!(calculate_feels_like(t2_k, windy, 60.0), t2_k);
+        let t2_k = celsius_to_kelvin(27.5);
+        assert_eq!(
+            Some(calculate_feels_like(t2_k, windy, 60.0)),
+            calculate_heat_index_simplified(t2_k, 60.0)
+        );
+    }
+
+    #[test]
+    fn test_dew_point_from_vapour_pressure() {
+        for t_k in [193.15, 253.15, 273.16, 293.15, 323.15, 363.15] {
+            let es = calculate_saturation_vapour_pressure(t_k);
+            assert_relative_eq!(
+                calculate_dew_point_from_vapour_pressure(es),
+                t_k,
+                epsilon = 1e-9
+            );
+        }
+
+        // Relative humidity to dew point and back through the same saturation formula
+        let (t2_k, rh) = (303.15, 45.0);
+        let td_k = calculate_dew_point_from_vapour_pressure(relative_humidity_to_vapour_pressure(
+            t2_k, rh,
+        ));
+        assert_relative_eq!(
+            vapour_pressure_to_relative_humidity(t2_k, calculate_saturation_vapour_pressure(td_k)),
+            rh,
+            epsilon = 1e-9
+        );
+
+        assert!(calculate_dew_point_from_vapour_pressure(0.0).is_nan());
+        assert!(calculate_dew_point_from_vapour_pressure(-1.0).is_nan());
+        assert!(calculate_dew_point_from_vapour_pressure(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_dew_point_from_wet_bulb() {
+        // Psychrometric tables give a dew point of 17.6°C for 25°C dry bulb and 20°C wet bulb at
+        // 1013.25 hPa
+        let td_k = calculate_dew_point_from_wet_bulb(
+            celsius_to_kelvin(25.0),
+            celsius_to_kelvin(20.0),
+            1013.25,
+        );
+        assert_relative_eq!(kelvin_to_celsius(td_k), 17.6, epsilon = 0.2);
+
+        // Saturated air: no depression, so the dew point is the wet bulb temperature
+        let td_k = calculate_dew_point_from_wet_bulb(293.15, 293.15, 1013.25);
+        assert_relative_eq!(td_k, 293.15, epsilon = 1e-9);
+
+        let high = calculate_dew_point_from_wet_bulb(298.15, 293.15, 1013.25);
+        let low = calculate_dew_point_from_wet_bulb(298.15, 293.15, 700.0);
+        assert!(low > high);
+    }
+
+    #[test]
+    fn test_humidex_checked() {
+        assert_eq!(calculate_humidex_checked(300.0, 0.0), None);
+        assert_eq!(calculate_humidex_checked(300.0, -5.0), None);
+        assert_eq!(calculate_humidex_checked(300.0, f64::NAN), None);
+        assert_eq!(calculate_humidex_checked(f64::INFINITY, 290.0), None);
+        assert_eq!(
+            calculate_humidex_checked(300.0, 290.0),
+            Some(calculate_humidex(300.0, 290.0))
+        );
+    }
+
+    #[test]
+    fn test_wbgt_from_vapour_pressure() {
+        for (t2_k, mrt, va, td_k) in [(305.0, 320.0, 2.0, 295.0), (290.0, 300.0, 5.0, 280.0)] {
+            // Tetens vapour pressure at the dew point, as used by calculate_relative_humidity_percent
+            let td_c: f64 = kelvin_to_celsius(td_k);
+            let eh_pa = 6.11 * 10.0_f64.powf(7.5 * td_c / (237.3 + td_c));
+
+            assert_relative_eq!(
+                calculate_wbgt_from_vapour_pressure(t2_k, mrt, va, eh_pa),
+                calculate_wbgt(t2_k, mrt, va, td_k),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_relative_humidity_vapour_pressure_round_trip() {
+        for t2_c in [-30.0, 0.0, 15.0, 35.0] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            for rh in [1.0, 25.0, 60.0, 100.0] {
+                let eh_pa = relative_humidity_to_vapour_pressure(t2_k, rh);
+                assert_relative_eq!(
+                    vapour_pressure_to_relative_humidity(t2_k, eh_pa),
+                    rh,
+                    max_relative = 1e-12
+                );
+            }
+        }
+
+        let t2_k = celsius_to_kelvin(20.0);
+        assert_eq!(
+            relative_humidity_to_vapour_pressure(t2_k, 100.0),
+            calculate_saturation_vapour_pressure(t2_k)
+        );
+    }
+
+    #[test]
+    fn test_checked_conversions_reject_below_absolute_zero() {
+        assert_relative_eq!(celsius_to_kelvin_checked(25.0).unwrap(), 298.15);
+        assert_eq!(celsius_to_kelvin_checked(-273.15), Ok(0.0));
+        assert_eq!(
+            celsius_to_kelvin_checked(-300.0),
+            Err(ThermofeelError::OutOfValidRange {
+                name: "tc",
+                value: -300.0,
+                min: -273.15,
+                max: f64::INFINITY,
+            })
+        );
+        assert!(celsius_to_kelvin_checked(f64::NAN).is_err());
+        // The infallible version is unchanged
+        assert_relative_eq!(celsius_to_kelvin(-300.0), -26.85, epsilon = 1e-9);
+
+        assert_relative_eq!(fahrenheit_to_kelvin_checked(32.0).unwrap(), 273.15);
+        assert_eq!(fahrenheit_to_kelvin_checked(-459.67), Ok(0.0));
+        assert!(matches!(
+            fahrenheit_to_kelvin_checked(-500.0),
+            Err(ThermofeelError::OutOfValidRange { value: -500.0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_rankine_conversions() {
+        // Absolute zero and the freezing and boiling points of water
+        for (t_k, t_r, t_f) in [
+            (0.0, 0.0, -459.67),
+            (273.15, 491.67, 32.0),
+            (373.15, 671.67, 212.0),
+        ] {
+            assert_relative_eq!(kelvin_to_rankine(t_k), t_r, epsilon = 1e-9);
+            assert_relative_eq!(rankine_to_kelvin(t_r), t_k, epsilon = 1e-9);
+            assert_relative_eq!(fahrenheit_to_rankine(t_f), t_r, epsilon = 1e-9);
+            assert_relative_eq!(rankine_to_fahrenheit(t_r), t_f, epsilon = 1e-9);
+
+            assert_relative_eq!(
+                rankine_to_kelvin(kelvin_to_rankine(t_k)),
+                t_k,
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                rankine_to_fahrenheit(kelvin_to_rankine(t_k)),
+                kelvin_to_fahrenheit(t_k),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_relative_humidity() {
+        assert_eq!(normalize_relative_humidity(100.3), 100.0);
+        assert_eq!(normalize_relative_humidity(-0.5), 0.0);
+        assert_eq!(normalize_relative_humidity(55.0), 55.0);
+        assert!(normalize_relative_humidity(f64::NAN).is_nan());
+
+        // A dew point 0.05 K above the air temperature gives about 100.3%
+        let (t2_k, td_k) = (293.15, 293.2);
+        let rh = calculate_relative_humidity_percent(t2_k, td_k);
+        assert_relative_eq!(rh, 100.3, epsilon = 0.05);
+        assert_eq!(
+            calculate_relative_humidity_percent_clamped(t2_k, td_k),
+            100.0
+        );
+    }
+
+    #[test]
+    fn test_humidex_from_rh() {
+        for (t2_k, td_k) in [(303.15, 295.15), (308.15, 288.15), (298.15, 297.15)] {
+            let rh = calculate_relative_humidity_percent(t2_k, td_k);
+            assert_relative_eq!(
+                calculate_humidex_from_rh(t2_k, rh),
+                calculate_humidex(t2_k, td_k),
+                epsilon = 0.2
+            );
+        }
+
+        let td_k = calculate_dew_point_from_relative_humidity(60.0, 303.15);
+        assert_eq!(
+            calculate_humidex_from_rh(303.15, 60.0),
+            calculate_humidex(303.15, td_k)
+        );
+    }
+
+    #[test]
+    fn test_bgt_custom() {
+        for (t2_k, mrt, va) in [
+            (300.0, 330.0, 2.0),
+            (290.0, 295.0, 6.0),
+            (280.0, 285.0, 0.0),
+        ] {
+            assert_eq!(
+                calculate_bgt(t2_k, mrt, va),
+                calculate_bgt_custom(t2_k, mrt, va, 0.15, 0.95)
+            );
+        }
+
+        // A small globe sits closer to the air temperature
+        let standard = calculate_bgt_custom(300.0, 330.0, 2.0, 0.15, 0.95);
+        let small = calculate_bgt_custom(300.0, 330.0, 2.0, 0.05, 0.95);
+        assert!(small < standard && small > 300.0);
+
+        // and round-trips through the matching MRT calculation
+        assert_relative_eq!(
+            calculate_mrt_from_globe(300.0, small, 2.0, 0.05, 0.95),
+            330.0,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_output_unit() {
+        let utci_k = calculate_utci(303.15, 2.0, 313.15, Some(293.15), None);
+        let utci = |unit| calculate_utci_in(303.15, 2.0, 313.15, Some(293.15), None, unit);
+
+        assert_eq!(utci(OutputUnit::Kelvin), utci_k);
+        assert_relative_eq!(utci(OutputUnit::Celsius), utci_k - 273.15, epsilon = 1e-9);
+        assert_relative_eq!(
+            utci(OutputUnit::Fahrenheit),
+            (utci_k - 273.15) * 1.8 + 32.0,
+            epsilon = 1e-9
+        );
+
+        let hi_k = calculate_heat_index_simplified(300.0, 50.0).unwrap();
+        assert_relative_eq!(
+            calculate_heat_index_simplified_in(300.0, 50.0, OutputUnit::Fahrenheit).unwrap(),
+            kelvin_to_fahrenheit(hi_k),
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            calculate_heat_index_simplified_in(280.0, 50.0, OutputUnit::Celsius),
+            None
+        );
+        assert_relative_eq!(
+            OutputUnit::Fahrenheit.from_kelvin(300.0),
+            80.33,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            OutputUnit::Celsius.from_kelvin(300.0),
+            26.85,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_solve_dew_point_for_humidex() {
+        let t2_k = celsius_to_kelvin(32.0);
+
+        for target_c in [35.0, 40.0, 45.0] {
+            let target_k = celsius_to_kelvin(target_c);
+            let td_k = solve_dew_point_for_humidex(target_k, t2_k).unwrap();
+            assert!(td_k < t2_k);
+            assert_relative_eq!(calculate_humidex(t2_k, td_k), target_k, epsilon = 1e-9);
+        }
+
+        // Needs more vapour than saturated air at 32°C holds
+        assert_eq!(
+            solve_dew_point_for_humidex(celsius_to_kelvin(60.0), t2_k),
+            None
+        );
+        // Needs negative vapour pressure
+        assert_eq!(
+            solve_dew_point_for_humidex(celsius_to_kelvin(20.0), t2_k),
+            None
+        );
+    }
+
+    #[test]
+    fn test_solve_vapour_pressure_from_humidex() {
+        let t2_k = celsius_to_kelvin(30.0);
+
+        for td_c in [5.0, 15.0, 25.0] {
+            let td_k = celsius_to_kelvin(td_c);
+            let humidex_k = calculate_humidex(t2_k, td_k);
+            let vp = solve_vapour_pressure_from_humidex(humidex_k, t2_k);
+            assert_relative_eq!(
+                vp,
+                6.11 * (5417.7530 * (1.0 / 273.16 - 1.0 / td_k)).exp(),
+                max_relative = 1e-9
+            );
+
+            let derived_td_k = solve_dew_point_for_humidex(humidex_k, t2_k).unwrap();
+            assert_relative_eq!(derived_td_k, td_k, epsilon = 1e-9);
+        }
+
+        // Humidex equal to the air temperature means exactly 10 hPa
+        assert_relative_eq!(
+            solve_vapour_pressure_from_humidex(t2_k, t2_k),
+            10.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_detect_heatwave() {
+        let threshold_k = celsius_to_kelvin(30.0);
+        let series_c = [
+            25.0, 31.0, 32.0, 33.0, 29.0, // three day run
+            31.0, 28.0, // too short
+            30.0, 34.0, 35.0, 31.0, 32.0, // four day run after a day at the threshold
+            27.0, 31.0, 31.0, // too short, at the end
+        ];
+        let series_k: Vec<f64> = series_c.iter().map(|&t| celsius_to_kelvin(t)).collect();
+
+        assert_eq!(
+            detect_heatwave(&series_k, threshold_k, 3),
+            vec![(1, 3), (8, 11)]
+        );
+        assert_eq!(
+            detect_heatwave(&series_k, threshold_k, 2),
+            vec![(1, 3), (8, 11), (13, 14)]
+        );
+        assert_eq!(detect_heatwave(&series_k, threshold_k, 5), vec![]);
+
+        // NaN days end a run
+        let mut gappy = series_k.clone();
+        gappy[2] = f64::NAN;
+        assert_eq!(detect_heatwave(&gappy, threshold_k, 3), vec![(8, 11)]);
+    }
+
+    #[test]
+    fn test_exceedance_runs() {
+        let threshold_k = celsius_to_kelvin(30.0);
+        let series_c = [
+            31.0,
+            33.0,
+            29.0, // two hour run at the start, peak 33
+            30.0,
+            28.0, // at the threshold is not above it
+            35.0, // one hour run
+            29.0,
+            32.0,
+            36.0,
+            34.0,
+            f64::NAN,
+            31.0, // NaN splits a run
+            20.0,
+            31.5,
+            30.5, // run at the end
+        ];
+        let series_k: Vec<f64> = series_c.iter().map(|&t| celsius_to_kelvin(t)).collect();
+
+        let runs: Vec<(usize, usize, f64)> = exceedance_runs(&series_k, threshold_k)
+            .into_iter()
+            .map(|(start, end, peak_k)| (start, end, kelvin_to_celsius(peak_k)))
+            .collect();
+        let expected = [
+            (0, 1, 33.0),
+            (5, 5, 35.0),
+            (7, 9, 36.0),
+            (11, 11, 31.0),
+            (13, 14, 31.5),
+        ];
+        assert_eq!(runs.len(), expected.len());
+        for (run, expected) in runs.iter().zip(expected) {
+            assert_eq!((run.0, run.1), (expected.0, expected.1));
+            assert_relative_eq!(run.2, expected.2, epsilon = 1e-9);
+        }
+
+        assert_eq!(exceedance_runs(&[], threshold_k), vec![]);
+        assert_eq!(exceedance_runs(&series_k, celsius_to_kelvin(40.0)), vec![]);
+    }
+
+    #[test]
+    fn test_liljegren() {
+        let night = SolarRadiation::default();
+        let sun = SolarRadiation {
+            solar: 900.0,
+            direct_fraction: 0.8,
+            cossza: 0.85,
+        };
+
+        for (t2_c, rh, va) in [(30.0, 50.0, 2.0), (35.0, 30.0, 1.0), (25.0, 90.0, 3.0)] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            let td_k = calculate_dew_point_from_relative_humidity(rh, t2_k);
+
+            // Without sun the natural wet bulb lies between the dew point and the air temperature,
+            // close to the psychrometric wet bulb
+            let nwb_night = calculate_natural_wet_bulb_liljegren(t2_k, rh, va, night, 1013.25);
+            assert!(td_k < nwb_night && nwb_night < t2_k);
+            assert_relative_eq!(nwb_night, calculate_wbt(t2_k, rh), epsilon = 1.0);
+
+            // Sunshine warms both the wick and the globe
+            let nwb_sun = calculate_natural_wet_bulb_liljegren(t2_k, rh, va, sun, 1013.25);
+            let tg_sun = calculate_globe_temperature_liljegren(t2_k, rh, va, sun, 1013.25);
+            assert!(nwb_sun > nwb_night);
+            assert!(tg_sun > calculate_globe_temperature_liljegren(t2_k, rh, va, night, 1013.25));
+            assert!(tg_sun > t2_k);
+
+            assert_relative_eq!(
+                calculate_wbgt_liljegren(t2_k, rh, va, sun, 1013.25),
+                0.7 * nwb_sun + 0.2 * tg_sun + 0.1 * t2_k,
+                epsilon = 1e-9
+            );
+        }
+
+        // Regression values for the documented inputs, in Celsius. These are this port's outputs,
+        // not yet cross-checked against a build of the reference wbgt.c, which also takes a 2m wind
+        // speed where these are scaled from 10m.
+        for ((t2_c, rh, va), radiation, expected) in [
+            ((30.0, 50.0, 2.0), night, (21.9349, 29.0168, 24.1578)),
+            ((30.0, 50.0, 2.0), sun, (24.5572, 47.2305, 29.6361)),
+            ((35.0, 30.0, 1.0), sun, (25.4305, 55.9347, 32.4883)),
+            ((25.0, 90.0, 3.0), sun, (25.6426, 40.3380, 28.5174)),
+        ] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            let nwb = calculate_natural_wet_bulb_liljegren(t2_k, rh, va, radiation, 1013.25);
+            let tg = calculate_globe_temperature_liljegren(t2_k, rh, va, radiation, 1013.25);
+            let wbgt = calculate_wbgt_liljegren(t2_k, rh, va, radiation, 1013.25);
+            assert_relative_eq!(kelvin_to_celsius(nwb), expected.0, epsilon = 1e-3);
+            assert_relative_eq!(kelvin_to_celsius(tg), expected.1, epsilon = 1e-3);
+            assert_relative_eq!(kelvin_to_celsius(wbgt), expected.2, epsilon = 1e-3);
+        }
+
+        // A wick in saturated air with no sun cannot evaporate, so it sits near air temperature
+        let t2_k = celsius_to_kelvin(30.0);
+        let nwb = calculate_natural_wet_bulb_liljegren(t2_k, 100.0, 2.0, night, 1013.25);
+        assert_relative_eq!(nwb, t2_k, epsilon = 0.2);
+
+        // Perfectly dry air is valid: the wick is well below air temperature, near the
+        // psychrometric wet bulb
+        let dry = calculate_natural_wet_bulb_liljegren(t2_k, 0.0, 2.0, night, 1013.25);
+        assert!(dry.is_finite());
+        assert!(dry < t2_k - 10.0);
+        assert_relative_eq!(dry, calculate_wbt(t2_k, 0.0), epsilon = 1.5);
+        assert!(calculate_wbgt_liljegren(t2_k, 0.0, 2.0, sun, 1013.25).is_finite());
+    }
+
+    #[test]
+    fn test_utci_humidity() {
+        let (t2_k, va, mrt, td_k) = (303.15, 2.0, 313.15, 293.15);
+
+        let rh = calculate_relative_humidity_percent(t2_k, td_k);
+        let eh_pa = relative_humidity_to_vapour_pressure(t2_k, rh);
+        let expected = calculate_utci(t2_k, va, mrt, Some(td_k), None);
+
+        for humidity in [
+            HumidityInput::DewPoint(td_k),
+            HumidityInput::VapourPressureHpa(eh_pa),
+            HumidityInput::RelativeHumidity(rh),
+        ] {
+            assert_relative_eq!(
+                calculate_utci_humidity(t2_k, va, mrt, humidity),
+                expected,
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_projected_area_factor() {
+        // Sun on the horizon, at 30 degrees elevation and overhead.
+        assert_relative_eq!(calculate_projected_area_factor(0.0), 0.308, epsilon = 1e-12);
+        assert_relative_eq!(
+            calculate_projected_area_factor(0.5),
+            0.26833,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            calculate_projected_area_factor(1.0),
+            0.07847,
+            epsilon = 1e-5
+        );
+
+        let fp: Vec<f64> = [0.0, 0.25, 0.5, 0.75, 1.0]
+            .iter()
+            .map(|&c| calculate_projected_area_factor(c))
+            .collect();
+        assert!(fp.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_mean_radiant_temperature_longwave() {
+        let (ssrd, ssr, dsrp, strd, fdir, strr, cossza) =
+            (600.0, 480.0, 450.0, 350.0, 400.0, -80.0, 0.7);
+        let lur = strd - strr;
+
+        assert_relative_eq!(
+            calculate_mean_radiant_temperature_longwave(ssrd, ssr, dsrp, strd, fdir, lur, cossza),
+            calculate_mean_radiant_temperature(ssrd, ssr, dsrp, strd, fdir, strr, cossza),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_equivalent_temperature() {
+        let t2_k = 293.15;
+        // e = 11.69 hPa at 20°C and 50%, so w = 7.26 g/kg and L w / cp = 17.7 K.
+        assert_relative_eq!(
+            calculate_mixing_ratio(t2_k, 50.0, 1013.25),
+            0.007262,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            calculate_equivalent_temperature(t2_k, 50.0, 1013.25),
+            310.87,
+            epsilon = 0.02
+        );
+        assert_relative_eq!(calculate_equivalent_temperature(t2_k, 0.0, 1013.25), t2_k);
+    }
+
+    #[test]
+    fn test_wbt_method() {
+        for (t2_k, rh) in [(293.15, 50.0), (303.15, 80.0), (313.15, 20.0)] {
+            assert_eq!(
+                calculate_wbt_method(t2_k, rh, 1013.25, WetBulbMethod::StullRegression),
+                calculate_wbt(t2_k, rh)
+            );
+        }
+
+        // Exact pseudoadiabatic wet bulb temperatures from Bolton's equivalent potential temperature.
+        let dj = |t2_k, rh, p| calculate_wbt_method(t2_k, rh, p, WetBulbMethod::DaviesJones);
+        assert_relative_eq!(dj(293.15, 50.0, 1013.25), 286.8797, epsilon = 0.01);
+        assert_relative_eq!(dj(303.15, 80.0, 1013.25), 300.2407, epsilon = 0.01);
+        assert_relative_eq!(dj(313.15, 20.0, 1013.25), 294.9175, epsilon = 0.01);
+
+        // Saturated air is its own wet bulb, and lower pressure speeds evaporation.
+        assert_relative_eq!(dj(298.15, 100.0, 1013.25), 298.15, epsilon = 0.01);
+        assert!(dj(303.15, 50.0, 700.0) < dj(303.15, 50.0, 1013.25));
+    }
+
+    #[test]
+    fn test_wbt_auto() {
+        // In range: Stull's regression
+        let (t2_k, rh) = (303.15, 60.0);
+        assert_eq!(
+            select_wet_bulb_method(t2_k, rh, 1013.25),
+            WetBulbMethod::StullRegression
+        );
+        assert_eq!(
+            calculate_wbt_auto(t2_k, rh, 1013.25),
+            calculate_wbt(t2_k, rh)
+        );
+
+        // Out of range in each criterion: Davies-Jones
+        for (t2_k, rh, p) in [
+            (303.15, 2.0, 1013.25),
+            (303.15, 100.0, 1013.25),
+            (celsius_to_kelvin(-30.0), 60.0, 1013.25),
+            (celsius_to_kelvin(55.0), 20.0, 1013.25),
+            (303.15, 60.0, 700.0),
+            (303.15, f64::NAN, 1013.25),
+        ] {
+            assert_eq!(
+                select_wet_bulb_method(t2_k, rh, p),
+                WetBulbMethod::DaviesJones
+            );
+        }
+        assert_eq!(
+            calculate_wbt_auto(303.15, 60.0, 700.0),
+            calculate_wbt_method(303.15, 60.0, 700.0, WetBulbMethod::DaviesJones)
+        );
+
+        // The range bounds are included
+        assert_eq!(
+            select_wet_bulb_method(celsius_to_kelvin(50.0), 99.0, 950.0),
+            WetBulbMethod::StullRegression
+        );
+    }
+
+    #[test]
+    fn test_normal_effective_temperature_bad_wind() {
+        let (t2_k, rh) = (290.0, 60.0);
+        let calm = calculate_normal_effective_temperature(t2_k, 0.0, rh);
+
+        for va in [-0.1, -5.0, -1e6, f64::NEG_INFINITY] {
+            assert_eq!(calculate_normal_effective_temperature(t2_k, va, rh), calm);
+        }
+        assert!(calculate_normal_effective_temperature(t2_k, f64::NAN, rh).is_nan());
+    }
+
+    #[test]
+    fn test_relative_humidity_from_wet_bulb() {
+        // Psychrometric chart at sea level: 25°C dry bulb and 20°C wet bulb is about 63%.
+        let rh = calculate_relative_humidity_from_wet_bulb(298.15, 293.15, 1013.25);
+        assert_relative_eq!(rh, 63.0, epsilon = 0.5);
+
+        assert_relative_eq!(
+            calculate_relative_humidity_from_wet_bulb(298.15, 298.15, 1013.25),
+            100.0,
+            epsilon = 1e-9
+        );
+
+        // Consistent with the dew point found from the same readings.
+        let td_k = calculate_dew_point_from_wet_bulb(298.15, 293.15, 1013.25);
+        let e = relative_humidity_to_vapour_pressure(298.15, rh);
+        assert_relative_eq!(
+            calculate_dew_point_from_vapour_pressure(e),
+            td_k,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_saturation_vapour_pressure_ice_hardy() {
+        let water = calculate_saturation_vapour_pressure(constants::TRIPLE_POINT_K);
+        let ice = calculate_saturation_vapour_pressure_ice_hardy(constants::TRIPLE_POINT_K);
+        assert_relative_eq!(ice, 6.11657, epsilon = 1e-4);
+        assert_relative_eq!(ice, water, epsilon = 1e-5);
+
+        for t2_k in [233.15, 253.15, 263.15, 273.0] {
+            let ice = calculate_saturation_vapour_pressure_ice_hardy(t2_k);
+            let ifs = calculate_saturation_vapour_pressure_multiphase(t2_k, Phase::Ice);
+            assert!(ice < calculate_saturation_vapour_pressure(t2_k));
+            assert_relative_eq!(ice, ifs, max_relative = 5e-3);
+        }
+    }
+
+    #[test]
+    fn test_heat_index_blended() {
+        // 90°F and 50% is 95°F on the NOAA heat index chart.
+        let t2_k = fahrenheit_to_kelvin(90.0);
+        let td_k = calculate_dew_point_from_relative_humidity(50.0, t2_k);
+        assert_relative_eq!(
+            kelvin_to_fahrenheit(calculate_heat_index_blended(t2_k, td_k)),
+            94.6,
+            epsilon = 0.5
+        );
+
+        for rh in [5.0, 30.0, 60.0, 90.0, 100.0] {
+            let hi_f = |t2_f: f64| {
+                let t2_k = fahrenheit_to_kelvin(t2_f);
+                let td_k = calculate_dew_point_from_relative_humidity(rh, t2_k);
+                kelvin_to_fahrenheit(calculate_heat_index_blended(t2_k, td_k))
+            };
+            let mut previous = hi_f(76.0);
+            for i in 1..=8000 {
+                let current = hi_f(76.0 + i as f64 * 0.001);
+                assert!((current - previous).abs() < 0.01, "jump at rh {rh}");
+                previous = current;
+            }
+        }
+    }
+
+    #[test]
+    fn test_wind_chill_heat_loss() {
+        // -20°C at 10 m/s: (10.45 + 10√10 - 10)(53) = 1699.8 kcal m-2 h-1.
+        let t2_k = celsius_to_kelvin(-20.0);
+        let heat_loss = calculate_wind_chill_heat_loss(t2_k, 10.0);
+        assert_relative_eq!(heat_loss, 1976.9, epsilon = 0.1);
+
+        let windchill_c = 33.0 - heat_loss / 1.163 / 22.034;
+        assert_relative_eq!(
+            celsius_to_kelvin(windchill_c),
+            calculate_wind_chill_siple(t2_k, 10.0),
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            calculate_wind_chill_heat_loss(celsius_to_kelvin(33.0), 10.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let cases = [
+            (
+                ThermofeelError::MismatchedSliceLengths {
+                    expected: 3,
+                    got: 2,
+                },
+                "input has length 2 but expected length 3",
+            ),
+            (
+                ThermofeelError::OutOfValidRange {
+                    name: "va",
+                    value: 20.0,
+                    min: 0.5,
+                    max: 17.0,
+                },
+                "`va` = 20 is outside [0.5, 17]",
+            ),
+            (
+                ThermofeelError::OutOfValidRange {
+                    name: "wvp",
+                    value: 6.0,
+                    min: f64::NEG_INFINITY,
+                    max: 5.0,
+                },
+                "`wvp` = 6 is outside [-inf, 5]",
+            ),
+            (
+                ThermofeelError::OutOfValidRange {
+                    name: "va",
+                    value: -1.0,
+                    min: 0.0,
+                    max: f64::INFINITY,
+                },
+                "`va` = -1 is outside [0, inf]",
+            ),
+            (
+                ThermofeelError::MissingHumidityInput,
+                "missing input ehPa or td_k",
+            ),
+            (
+                ThermofeelError::RootNotBracketed { lo: 0.0, hi: 1.5 },
+                "no sign change to bracket a root between 0 and 1.5",
+            ),
+            (
+                ThermofeelError::NoConvergence { iterations: 50 },
+                "did not converge within 50 iterations",
+            ),
+        ];
+
+        for (error, message) in cases {
+            assert_eq!(error.to_string(), message);
+            let boxed: Box<dyn std::error::Error> = Box::new(error);
+            assert_eq!(boxed.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_mrt_from_bgt_regime() {
+        let (t2_k, bgt_k) = (300.0, 310.0);
+
+        // Forced convection vanishes in calm air, leaving the globe at the mean radiant temperature.
+        assert_relative_eq!(
+            calculate_mrt_from_bgt(t2_k, bgt_k, 0.0),
+            bgt_k,
+            epsilon = 1e-9
+        );
+        let natural = calculate_mrt_from_globe_natural(t2_k, bgt_k, 0.15, 0.95);
+        assert!(natural > bgt_k + 5.0);
+        assert_eq!(calculate_mrt_from_bgt_regime(t2_k, bgt_k, 0.0), natural);
+
+        // 1.4 (10 / 0.15)^0.25 = 4.0 W m-2 K-1
+        let h = 1.4 * (10.0_f64 / 0.15).powf(0.25);
+        let expected = (bgt_k.powi(4) + h / (0.95 * 5.67e-8) * 10.0).powf(0.25);
+        assert_relative_eq!(natural, expected, epsilon = 1e-9);
+
+        for va in [1.0, 5.0] {
+            assert_eq!(
+                calculate_mrt_from_bgt_regime(t2_k, bgt_k, va),
+                calculate_mrt_from_bgt(t2_k, bgt_k, va)
+            );
+        }
+
+        // Both regimes share one forced convection coefficient, so the switch between them is
+        // continuous rather than a jump of about 0.06 K.
+        let mrt: Vec<f64> = (0..=2000)
+            .map(|i| calculate_mrt_from_bgt_regime(t2_k, bgt_k, i as f64 * 1e-4))
+            .collect();
+        for pair in mrt.windows(2) {
+            assert!((pair[1] - pair[0]).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_wbgt_bernard() {
+        let (t2_k, rh) = (celsius_to_kelvin(35.0), 50.0);
+        let tpwb_k = calculate_wbt(t2_k, rh);
+
+        // At 1 m/s C = 0.96, so WBGT = 0.7 (Ta - 0.96 (Ta - Tpwb)) + 0.3 Ta.
+        let expected = 0.7 * (t2_k - 0.96 * (t2_k - tpwb_k)) + 0.3 * t2_k;
+        assert_relative_eq!(
+            calculate_wbgt_bernard(t2_k, rh, 1.0),
+            expected,
+            epsilon = 1e-9
+        );
+
+        // Still air keeps the wick warmer than the psychrometric wet bulb; fast air does not.
+        let calm = calculate_wbgt_bernard(t2_k, rh, 0.0);
+        assert_relative_eq!(
+            calm,
+            0.7 * (t2_k - 0.85 * (t2_k - tpwb_k)) + 0.3 * t2_k,
+            epsilon = 1e-9
+        );
+        assert_eq!(calculate_wbgt_bernard(t2_k, rh, -1.0), calm);
+        assert_relative_eq!(
+            calculate_wbgt_bernard(t2_k, rh, 5.0),
+            0.7 * tpwb_k + 0.3 * t2_k,
+            epsilon = 1e-9
+        );
+        assert!(calculate_wbgt_bernard(t2_k, rh, 0.5) > calculate_wbgt_bernard(t2_k, rh, 2.0));
+
+        // Saturated air has no wet bulb depression.
+        assert_relative_eq!(
+            calculate_wbgt_bernard(t2_k, 100.0, 1.0),
+            t2_k,
+            epsilon = 0.2
+        );
+    }
+
+    #[test]
+    fn test_split_global_irradiance() {
+        for (ssrd, cossza) in [
+            (50.0, 0.5),
+            (400.0, 0.6),
+            (900.0, 0.85),
+            (1000.0, 0.8),
+            (10.0, -0.1),
+        ] {
+            let (fdir, diffuse) = split_global_irradiance(ssrd, cossza);
+            assert_relative_eq!(fdir + diffuse, ssrd, epsilon = 1e-9);
+            assert!(fdir >= 0.0 && diffuse >= 0.0);
+        }
+
+        // Overcast: kt = 68.05 / 680.5 = 0.1, so 99.1% is diffuse.
+        let (fdir, diffuse) = split_global_irradiance(68.05, 0.5);
+        assert_relative_eq!(diffuse / 68.05, 0.991, epsilon = 1e-9);
+        assert!(fdir < 1.0);
+
+        // Clear: kt above 0.8 leaves 16.5% diffuse.
+        let (_, diffuse) = split_global_irradiance(1000.0, 0.85);
+        assert_relative_eq!(diffuse, 165.0, epsilon = 1e-9);
+
+        // The quartic meets the linear and constant pieces.
+        let kd = |kt: f64| split_global_irradiance(kt * 1361.0, 1.0).1 / (kt * 1361.0);
+        assert_relative_eq!(kd(0.22), kd(0.22 + 1e-9), epsilon = 2e-3);
+        assert_relative_eq!(kd(0.8), kd(0.8 + 1e-9), epsilon = 2e-3);
+
+        assert_eq!(split_global_irradiance(10.0, 0.0), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_humiture() {
+        // 30°C with a 20°C dew point: e = 23.39 hPa, so 30 + 5/9 (13.39) = 37.44°C.
+        let (t2_k, td_k) = (celsius_to_kelvin(30.0), celsius_to_kelvin(20.0));
+        let humiture = calculate_humiture(t2_k, td_k);
+        assert_relative_eq!(kelvin_to_celsius(humiture), 37.4385, epsilon = 1e-3);
+        assert_relative_eq!(humiture, calculate_humidex(t2_k, td_k), epsilon = 0.2);
+
+        // Vapour pressure of 10 hPa leaves the temperature unchanged.
+        let td_k = celsius_to_kelvin(6.97);
+        assert_relative_eq!(calculate_humiture(t2_k, td_k), t2_k, epsilon = 0.01);
+    }
+
+    #[test]
+    fn test_relative_humidity_inputs_are_clamped() {
+        let (t2_k, va) = (305.0, 2.0);
+
+        assert_eq!(
+            calculate_wbgt_simple(t2_k, 120.0),
+            calculate_wbgt_simple(t2_k, 100.0)
+        );
+        assert_eq!(
+            calculate_wbgt_simple_at_pressure(t2_k, 120.0, 800.0),
+            calculate_wbgt_simple_at_pressure(t2_k, 100.0, 800.0)
+        );
+        assert_eq!(calculate_wbt(t2_k, 120.0), calculate_wbt(t2_k, 100.0));
+        assert_eq!(calculate_wbt(t2_k, -5.0), calculate_wbt(t2_k, 0.0));
+        assert_eq!(
+            calculate_heat_index_simplified(t2_k, 120.0),
+            calculate_heat_index_simplified(t2_k, 100.0)
+        );
+        assert_eq!(
+            calculate_apparent_temperature(t2_k, va, 120.0),
+            calculate_apparent_temperature(t2_k, va, 100.0)
+        );
+        assert_eq!(
+            calculate_apparent_temperature_with_radiation(t2_k, va, -5.0, 300.0),
+            calculate_apparent_temperature_with_radiation(t2_k, va, 0.0, 300.0)
+        );
+        assert_eq!(
+            calculate_normal_effective_temperature(t2_k, va, 120.0),
+            calculate_normal_effective_temperature(t2_k, va, 100.0)
+        );
+        assert_eq!(
+            calculate_humidex_from_rh(t2_k, 120.0),
+            calculate_humidex_from_rh(t2_k, 100.0)
+        );
+
+        assert!(calculate_wbt(t2_k, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_dew_point_auto() {
+        // Above freezing the dew point is reported, below it the warmer frost point.
+        let t2_k = celsius_to_kelvin(20.0);
+        assert_eq!(
+            calculate_dew_point_auto(50.0, t2_k),
+            calculate_dew_point_from_relative_humidity(50.0, t2_k)
+        );
+        let t2_k = celsius_to_kelvin(-10.0);
+        let frost = calculate_dew_point_auto(80.0, t2_k);
+        assert_eq!(
+            frost,
+            calculate_frost_point_from_relative_humidity(80.0, t2_k)
+        );
+        assert!(frost > calculate_dew_point_from_relative_humidity(80.0, t2_k));
+        assert!(frost < t2_k);
+
+        // Saturated over water at -10°C is supersaturated over ice: the frost point is above -10°C.
+        assert_relative_eq!(
+            calculate_frost_point_from_relative_humidity(100.0, t2_k),
+            celsius_to_kelvin(-8.88),
+            epsilon = 0.01
+        );
+
+        // Continuous as the dew point crosses 0°C, here by raising humidity at 5°C.
+        let t2_k = celsius_to_kelvin(5.0);
+        let mut previous = calculate_dew_point_auto(60.0, t2_k);
+        for i in 1..=2000 {
+            let current = calculate_dew_point_auto(60.0 + i as f64 * 0.005, t2_k);
+            assert!((current - previous).abs() < 0.01);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn test_apparent_temperature_noaa() {
+        // Heat index: 90°F and 50% is 95°F on the NWS chart.
+        let at = calculate_apparent_temperature_noaa(fahrenheit_to_kelvin(90.0), 3.0, 50.0);
+        assert_relative_eq!(kelvin_to_fahrenheit(at), 94.6, epsilon = 0.1);
+
+        // Wind chill: 0°F at 15 mph is -19°F on the NWS chart.
+        let t2_k = fahrenheit_to_kelvin(0.0);
+        let va = 15.0 * 0.44704;
+        let at = calculate_apparent_temperature_noaa(t2_k, va, 50.0);
+        assert_eq!(at, calculate_wind_chill(t2_k, va));
+        assert_relative_eq!(kelvin_to_fahrenheit(at), -19.0, epsilon = 0.5);
+
+        // Mild, or cold but calm: the air temperature.
+        for (t2_f, va) in [(65.0, 5.0), (40.0, 1.0)] {
+            let t2_k = fahrenheit_to_kelvin(t2_f);
+            assert_eq!(calculate_apparent_temperature_noaa(t2_k, va, 50.0), t2_k);
+        }
+    }
+
+    #[test]
+    fn test_applicable_indices() {
+        let cold = applicable_indices(celsius_to_kelvin(-10.0), 5.0, 70.0);
+        assert!(cold.contains(&"wind_chill"));
+        assert!(!cold.contains(&"heat_index"));
+        assert!(!cold.contains(&"humidex"));
+        assert!(cold.contains(&"utci"));
+
+        let hot = applicable_indices(celsius_to_kelvin(35.0), 2.0, 50.0);
+        assert!(hot.contains(&"heat_index"));
+        assert!(hot.contains(&"humidex"));
+        assert!(!hot.contains(&"wind_chill"));
+        assert_eq!(
+            hot,
+            [
+                "utci",
+                "wbgt",
+                "humidex",
+                "apparent_temperature",
+                "heat_index",
+                "wbt",
+                "net"
+            ]
+        );
+
+        for (scenario, expect_wind_chill) in
+            [(COLD_WINDY_ARCTIC, true), (HOT_HUMID_TROPICAL, false)]
+        {
+            let rh = scenario.relative_humidity();
+            let applicable = applicable_indices(scenario.t2_k, scenario.va, rh);
+            assert_eq!(applicable.contains(&"wind_chill"), expect_wind_chill);
+            assert_eq!(applicable.contains(&"heat_index"), !expect_wind_chill);
+        }
+
+        // Calm air is below the UTCI wind range.
+        assert!(!applicable_indices(celsius_to_kelvin(20.0), 0.1, 50.0).contains(&"utci"));
+    }
+
+    #[test]
+    fn test_saturation_vapour_pressure_derivative() {
+        for t2_c in [-40.0, -10.0, 0.0, 15.0, 30.0, 50.0] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            let h = 1e-3;
+            let finite_difference = (calculate_saturation_vapour_pressure(t2_k + h)
+                - calculate_saturation_vapour_pressure(t2_k - h))
+                / (2.0 * h);
+            assert_relative_eq!(
+                calculate_saturation_vapour_pressure_derivative(t2_k),
+                finite_difference,
+                max_relative = 1e-7
+            );
+        }
+        assert_relative_eq!(
+            calculate_saturation_vapour_pressure_derivative(celsius_to_kelvin(15.0)),
+            1.10,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_wbgt_exposure() {
+        let (t2_k, va, td_k) = (celsius_to_kelvin(32.0), 1.5, celsius_to_kelvin(20.0));
+        let mrt = celsius_to_kelvin(55.0);
+
+        let sun = calculate_wbgt_exposure(t2_k, mrt, va, td_k, WbgtExposure::Sun);
+        assert_eq!(sun, calculate_wbgt(t2_k, mrt, va, td_k));
+
+        let shade = calculate_wbgt_exposure(t2_k, mrt, va, td_k, WbgtExposure::Shade);
+        let rh = calculate_relative_humidity_percent(t2_k, td_k);
+        let tw_c = kelvin_to_celsius(calculate_wbt(t2_k, rh));
+        assert_relative_eq!(
+            kelvin_to_celsius(shade),
+            0.7 * tw_c + 0.3 * 32.0,
+            epsilon = 1e-9
+        );
+
+        // The globe term is 0.2 (Tg - Ta) warmer in the sun.
+        let bgt_c = kelvin_to_celsius(calculate_bgt(t2_k, mrt, va));
+        assert!(sun > shade);
+        assert_relative_eq!(sun - shade, 0.2 * (bgt_c - 32.0), epsilon = 1e-9);
+
+        // The shade case ignores the mean radiant temperature and matches the sun case without radiation.
+        let no_radiation = calculate_wbgt_exposure(t2_k, t2_k, va, td_k, WbgtExposure::Shade);
+        assert_eq!(no_radiation, shade);
+        assert_eq!(
+            calculate_wbgt_exposure(t2_k, t2_k, va, td_k, WbgtExposure::Sun),
+            shade
+        );
+    }
+
+    #[test]
+    fn test_relative_humidity_from_specific_humidity() {
+        // 10 g/kg at 1000 hPa is e = 10 / 0.62578 = 15.98 hPa; at 25°C es = 31.70 hPa.
+        let rh = calculate_relative_humidity_from_specific_humidity(
+            celsius_to_kelvin(25.0),
+            0.01,
+            1000.0,
+        );
+        assert_relative_eq!(rh, 50.41, epsilon = 0.01);
+
+        // Dry air, and a round trip through the vapour pressure.
+        assert_eq!(
+            calculate_relative_humidity_from_specific_humidity(290.0, 0.0, 1013.25),
+            0.0
+        );
+        let e = relative_humidity_to_vapour_pressure(290.0, 60.0);
+        let q = constants::MOLAR_MASS_RATIO * e / (850.0 - 0.378 * e);
+        assert_relative_eq!(
+            calculate_relative_humidity_from_specific_humidity(290.0, q, 850.0),
+            60.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_classifiers() {
+        let utci = [
+            (-40.01, UtciCategory::ExtremeColdStress),
+            (-40.0, UtciCategory::VeryStrongColdStress),
+            (0.0, UtciCategory::SlightColdStress),
+            (9.0, UtciCategory::NoThermalStress),
+            (25.99, UtciCategory::NoThermalStress),
+            (26.0, UtciCategory::ModerateHeatStress),
+            (46.0, UtciCategory::ExtremeHeatStress),
+        ];
+        for (utci_c, expected) in utci {
+            assert_eq!(
+                classify_utci(celsius_to_kelvin(utci_c)),
+                Some(expected),
+                "{utci_c}°C"
+            );
+        }
+
+        let heat_index = [
+            (79.9, HeatIndexCategory::NoRisk),
+            (80.0, HeatIndexCategory::Caution),
+            (90.0, HeatIndexCategory::ExtremeCaution),
+            (103.0, HeatIndexCategory::Danger),
+            (125.0, HeatIndexCategory::ExtremeDanger),
+        ];
+        for (hi_f, expected) in heat_index {
+            // Avoid rounding just below a boundary in the conversion.
+            let hi_k = fahrenheit_to_kelvin(hi_f) + 1e-9;
+            assert_eq!(classify_heat_index(hi_k), Some(expected), "{hi_f}°F");
+        }
+
+        let humidex = [
+            (29.99, HumidexCategory::NoDiscomfort),
+            (30.0, HumidexCategory::SomeDiscomfort),
+            (40.0, HumidexCategory::GreatDiscomfort),
+            (45.0, HumidexCategory::GreatDiscomfort),
+            (45.01, HumidexCategory::Dangerous),
+        ];
+        for (humidex_c, expected) in humidex {
+            assert_eq!(
+                classify_humidex(celsius_to_kelvin(humidex_c)),
+                Some(expected),
+                "{humidex_c}"
+            );
+        }
+
+        let wind_chill = [
+            (-9.99, WindChillRisk::Low),
+            (-10.0, WindChillRisk::Moderate),
+            (-28.0, WindChillRisk::High),
+            (-40.0, WindChillRisk::VeryHigh),
+            (-48.0, WindChillRisk::Severe),
+            (-55.0, WindChillRisk::Extreme),
+        ];
+        for (wind_chill_c, expected) in wind_chill {
+            assert_eq!(
+                classify_wind_chill(celsius_to_kelvin(wind_chill_c)),
+                Some(expected),
+                "{wind_chill_c}"
+            );
+        }
+
+        // Missing data is not put in any band
+        assert_eq!(classify_utci(f64::NAN), None);
+        assert_eq!(classify_heat_index(f64::NAN), None);
+        assert_eq!(classify_humidex(f64::NAN), None);
+        assert_eq!(classify_wind_chill(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_cattle_thi() {
+        // NRC (1971) livestock THI: 90°F at 50% is 81, 100°F at 20% is 81.5
+        let thi = calculate_cattle_thi(fahrenheit_to_kelvin(90.0), 50.0);
+        assert_relative_eq!(thi, 81.0, epsilon = 0.5);
+        assert_eq!(classify_cattle_thi(thi), Some(CattleHeatStress::Danger));
+        assert_relative_eq!(
+            calculate_cattle_thi(fahrenheit_to_kelvin(100.0), 20.0),
+            81.5,
+            epsilon = 0.5
+        );
+
+        // 25°C at 40% is comfortable for cattle
+        let thi = calculate_cattle_thi(celsius_to_kelvin(25.0), 40.0);
+        assert_relative_eq!(thi, 70.64, epsilon = 1e-9);
+        assert_eq!(classify_cattle_thi(thi), Some(CattleHeatStress::Normal));
+
+        for (thi, expected) in [
+            (74.99, CattleHeatStress::Normal),
+            (75.0, CattleHeatStress::Alert),
+            (79.0, CattleHeatStress::Danger),
+            (84.0, CattleHeatStress::Emergency),
+        ] {
+            assert_eq!(classify_cattle_thi(thi), Some(expected), "{thi}");
+        }
+        assert_eq!(classify_cattle_thi(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_comfort_summary() {
+        let hot = calculate_comfort_summary(
+            celsius_to_kelvin(40.0),
+            celsius_to_kelvin(28.0),
+            1.0,
+            celsius_to_kelvin(60.0),
+        );
+        assert_eq!(
+            hot,
+            ComfortSummary {
+                utci: Some(UtciCategory::ExtremeHeatStress),
+                heat_index: Some(HeatIndexCategory::ExtremeDanger),
+                humidex: Some(HumidexCategory::Dangerous),
+                wind_chill: None,
+            }
+        );
+
+        let cold = calculate_comfort_summary(
+            celsius_to_kelvin(-20.0),
+            celsius_to_kelvin(-25.0),
+            10.0,
+            celsius_to_kelvin(-20.0),
+        );
+        assert_eq!(cold.heat_index, None);
+        assert_eq!(cold.humidex, Some(HumidexCategory::NoDiscomfort));
+        assert_eq!(cold.wind_chill, Some(WindChillRisk::High));
+        assert!(cold.utci.unwrap() <= UtciCategory::StrongColdStress);
+
+        let missing = calculate_comfort_summary(f64::NAN, celsius_to_kelvin(20.0), 1.0, 300.0);
+        assert_eq!(missing.utci, None);
+        assert_eq!(missing.humidex, None);
+    }
+
+    #[test]
+    fn test_round_to() {
+        // Exact binary ties go to the even neighbour.
+        assert_eq!(round_to(0.5, 0), 0.0);
+        assert_eq!(round_to(1.5, 0), 2.0);
+        assert_eq!(round_to(2.5, 0), 2.0);
+        assert_eq!(round_to(-2.5, 0), -2.0);
+        assert_eq!(round_to(0.125, 2), 0.12);
+        assert_eq!(round_to(0.375, 2), 0.38);
+
+        // Decimal ties stored just below the tie are treated as ties once scaled, but not always.
+        assert_eq!(round_to(2.665, 2), 2.66);
+        assert_eq!(round_to(2.675, 2), 2.68);
+        assert_eq!(round_to(0.285, 2), 0.28);
+        assert_eq!(round_to(2.6751, 2), 2.68);
+        assert_eq!(round_to(300.123456789, 6), 300.123457);
+
+        assert_eq!(round_to(1e300, 10), 1e300);
+        assert_eq!(round_to(f64::INFINITY, 2), f64::INFINITY);
+        assert!(round_to(f64::NAN, 2).is_nan());
+        assert_eq!(round_to(0.1, u32::MAX), 0.1);
+    }
+
+    #[test]
+    fn test_all_indices_rounded() {
+        let indices = calculate_all(300.0, 290.0, 3.0, 310.0);
+        let rounded = indices.rounded(1);
+        assert_eq!(rounded.utci, round_to(indices.utci, 1));
+        assert_eq!(
+            rounded.heat_index,
+            indices.heat_index.map(|hi| round_to(hi, 1))
+        );
+        assert_eq!(rounded.wind_chill, None);
+        assert!((rounded.wbgt - indices.wbgt).abs() <= 0.05);
+    }
+
+    #[test]
+    fn test_apparent_temperature_steadman1979() {
+        // Steadman's (1979) tables as given by the NWS heat index chart, which reproduces them to
+        // within 1.3°F (Rothfusz 1990, NWS Technical Attachment SR 90-23): 80°F at 40% is 80°F
+        // and 90°F at 40% is 91°F. The linear fit is checked to within 1°F.
+        for (t2_f, rh, table_f) in [(80.0, 40.0, 80.0), (90.0, 40.0, 91.0)] {
+            let at = calculate_apparent_temperature_steadman1979(fahrenheit_to_kelvin(t2_f), rh);
+            assert_relative_eq!(kelvin_to_fahrenheit(at), table_f, epsilon = 1.0);
+        }
+
+        // 30°C and 50% is e = 2.114 kPa, so AT = -1.3 + 27.6 + 4.651.
+        let t2_k = celsius_to_kelvin(30.0);
+        let at = calculate_apparent_temperature_steadman1979(t2_k, 50.0);
+        assert_relative_eq!(kelvin_to_celsius(at), 30.95, epsilon = 0.01);
+
+        // Close to the NWS fit of the same tables at moderate heat.
+        let rh = 50.0;
+        let hi = calculate_apparent_temperature_noaa(t2_k, 0.0, rh);
+        assert!((at - hi).abs() < 1.0);
+
+        // Drier air feels cooler, and the Bureau of Meteorology form differs even in calm air.
+        assert!(calculate_apparent_temperature_steadman1979(t2_k, 20.0) < at);
+        assert!((calculate_apparent_temperature(t2_k, 0.0, rh) - at).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_utci_gradient() {
+        let utci = |t2_k: f64, va: f64, mrt: f64, wvp: f64| {
+            calculate_utci(t2_k, va, mrt, None, Some(wvp * 10.0))
+        };
+        let h = 1e-4;
+
+        for (t2_k, va, mrt, wvp) in [
+            (300.0, 3.0, 315.0, 2.0),
+            (265.0, 8.0, 260.0, 0.3),
+            (310.0, 0.5, 340.0, 3.5),
+            (290.0, 2.0, 290.0, 1.0),
+        ] {
+            let (d_dt2m, d_dva, d_dmrt, d_dwvp) = calculate_utci_gradient(t2_k, va, mrt, wvp);
+            let central = |f: &dyn Fn(f64) -> f64| (f(h) - f(-h)) / (2.0 * h);
+
+            let fd_t2m = central(&|d| utci(t2_k + d, va, mrt, wvp));
+            let fd_va = central(&|d| utci(t2_k, va + d, mrt, wvp));
+            let fd_mrt = central(&|d| utci(t2_k, va, mrt + d, wvp));
+            let fd_wvp = central(&|d| utci(t2_k, va, mrt, wvp + d));
+
+            assert_relative_eq!(d_dt2m, fd_t2m, epsilon = 1e-5);
+            assert_relative_eq!(d_dva, fd_va, epsilon = 1e-5);
+            assert_relative_eq!(d_dmrt, fd_mrt, epsilon = 1e-5);
+            assert_relative_eq!(d_dwvp, fd_wvp, epsilon = 1e-5);
+        }
+
+        // Wind cools and radiation warms in typical warm conditions.
+        let (_, d_dva, d_dmrt, _) = calculate_utci_gradient(300.0, 3.0, 315.0, 2.0);
+        assert!(d_dva < 0.0);
+        assert!(d_dmrt > 0.0);
+    }
+
+    #[test]
+    fn test_index_registry() {
+        let inputs = MeteoInputs {
+            t2_k: 270.0,
+            td_k: 265.0,
+            va: 6.0,
+            mrt: 268.0,
+        };
+        let all = calculate_all(inputs.t2_k, inputs.td_k, inputs.va, inputs.mrt);
+
+        let computed: Vec<(&str, Option<f64>)> = all_indices()
+            .iter()
+            .map(|index| (index.name(), index.compute(&inputs)))
+            .collect();
+        assert_eq!(
+            computed,
+            [
+                ("utci", Some(all.utci)),
+                ("wbgt", Some(all.wbgt)),
+                ("humidex", Some(all.humidex)),
+                ("apparent_temperature", Some(all.apparent_temperature)),
+                ("heat_index", all.heat_index),
+                ("wind_chill", all.wind_chill),
+                ("wbt", Some(all.wbt)),
+                ("net", Some(all.net)),
+            ]
+        );
+        assert_eq!(HeatIndex.compute(&inputs), None);
+        assert!(WindChill.compute(&inputs).is_some());
+    }
+
+    #[test]
+    fn test_scale_windspeed_checked() {
+        assert_eq!(scale_windspeed_checked(5.0, 0.005), None);
+        assert_eq!(scale_windspeed_checked(5.0, 0.0), None);
+        assert_eq!(scale_windspeed_checked(5.0, 0.01), None);
+        assert_eq!(scale_windspeed_checked(5.0, -2.0), None);
+        assert_eq!(scale_windspeed_checked(5.0, f64::NAN), None);
+
+        // Down to 1.1m and up to 50m.
+        let down = scale_windspeed_checked(5.0, 1.1).unwrap();
+        assert_eq!(down, scale_windspeed(5.0, 1.1));
+        assert!(down > 0.0 && down < 5.0);
+        assert!(scale_windspeed_checked(5.0, 50.0).unwrap() > 5.0);
+    }
+
+    #[test]
+    fn test_two_node_model() {
+        // Near-neutral office conditions. pythermalcomfort's two_nodes, a port of the ASHRAE 55
+        // Appendix D code, gives t_skin 33.7°C, t_core 36.9°C, w 0.1 and m_rsw 10.3 g m-2 h-1 for
+        // these inputs, rounded to one decimal.
+        let neutral = calculate_two_node_model(
+            celsius_to_kelvin(25.0),
+            celsius_to_kelvin(25.0),
+            0.3,
+            50.0,
+            1.2,
+            0.5,
+        );
+        assert_relative_eq!(
+            kelvin_to_celsius(neutral.skin_temperature),
+            33.7,
+            epsilon = 0.05
+        );
+        assert_relative_eq!(
+            kelvin_to_celsius(neutral.core_temperature),
+            36.9,
+            epsilon = 0.05
+        );
+        assert_relative_eq!(neutral.skin_wettedness, 0.1, epsilon = 0.005);
+        assert_relative_eq!(neutral.sweat_rate, 10.3, epsilon = 0.05);
+
+        // NaN inputs do not hang the clothing temperature iteration
+        let missing =
+            calculate_two_node_model(f64::NAN, celsius_to_kelvin(25.0), 0.3, 50.0, 1.2, 0.5);
+        assert!(missing.skin_temperature.is_nan());
+        assert!(missing.core_temperature.is_nan());
+        assert!(missing.skin_wettedness.is_nan());
+        assert!(missing.sweat_rate.is_nan());
+
+        // Hot and working: wettedness reaches its clothed limit.
+        let hot = calculate_two_node_model(
+            celsius_to_kelvin(35.0),
+            celsius_to_kelvin(35.0),
+            0.1,
+            50.0,
+            1.5,
+            0.5,
+        );
+        assert_eq!(hot.skin_wettedness, 0.59);
+        assert!(hot.core_temperature > neutral.core_temperature);
+        assert!(hot.sweat_rate > 100.0);
+
+        // Cold: skin cools, no sweating, only diffusion through the skin.
+        let cold = calculate_two_node_model(
+            celsius_to_kelvin(10.0),
+            celsius_to_kelvin(10.0),
+            0.5,
+            50.0,
+            1.0,
+            0.5,
+        );
+        assert!(kelvin_to_celsius(cold.skin_temperature) < 28.0);
+        assert_eq!(cold.sweat_rate, 0.0);
+        assert_eq!(cold.skin_wettedness, 0.06);
+    }
+
+    #[test]
+    fn test_saturation_vapour_pressure_enhanced() {
+        for t2_c in [-20.0, 0.0, 20.0, 40.0] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            let es = calculate_saturation_vapour_pressure(t2_k);
+            let enhanced = calculate_saturation_vapour_pressure_enhanced(t2_k, 1013.25);
+            assert!(enhanced > es);
+            assert_relative_eq!(enhanced, es, max_relative = 0.006);
+        }
+
+        // f = 1 + 1e-4 (7.2 + 1013.25 (0.0320 + 5.9e-6 * 400)) = 1.004202 at 20°C.
+        let t2_k = celsius_to_kelvin(20.0);
+        assert_relative_eq!(
+            calculate_saturation_vapour_pressure_enhanced(t2_k, 1013.25)
+                / calculate_saturation_vapour_pressure(t2_k),
+            1.004202,
+            epsilon = 1e-6
+        );
+        assert!(
+            calculate_saturation_vapour_pressure_enhanced(t2_k, 500.0)
+                < calculate_saturation_vapour_pressure_enhanced(t2_k, 1013.25)
+        );
+    }
+
+    #[test]
+    fn test_utci_from_globe() {
+        for (t2_k, va, globe_k, td_k) in
+            [(303.15, 2.0, 318.15, 293.15), (283.15, 5.0, 285.15, 275.15)]
+        {
+            let mrt = calculate_mrt_from_globe(t2_k, globe_k, va, 0.15, 0.95);
+            let expected = calculate_utci(t2_k, va, mrt, Some(td_k), None);
+            assert_eq!(calculate_utci_from_globe(t2_k, va, globe_k, td_k), expected);
+        }
+
+        // A globe warmed by the sun raises UTCI above the shaded value.
+        let shaded = calculate_utci_from_globe(303.15, 2.0, 303.15, 293.15);
+        let sunny = calculate_utci_from_globe(303.15, 2.0, 318.15, 293.15);
+        assert!(sunny > shaded + 2.0);
+    }
+
+    #[test]
+    fn test_psychrometric_helpers() {
+        // 1005.7 * 1013.25 / (0.622 * 2.45e6)
+        assert_relative_eq!(
+            calculate_psychrometric_constant(1013.25),
+            0.66870,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            calculate_psychrometric_constant(500.0) / calculate_psychrometric_constant(1000.0),
+            0.5
+        );
+
+        assert_relative_eq!(
+            calculate_wet_bulb_depression(celsius_to_kelvin(30.0), celsius_to_kelvin(22.0)),
+            8.0,
+            epsilon = 1e-12
+        );
+        assert_eq!(calculate_wet_bulb_depression(290.0, 290.0), 0.0);
+    }
+
+    #[test]
+    fn test_utci_with_validity() {
+        let (t2_k, mrt, td_k) = (300.0, 310.0, 290.0);
+
+        let (utci, valid) = calculate_utci_with_validity(t2_k, 3.0, mrt, Some(td_k), None);
+        assert!(valid);
+        assert_eq!(utci, calculate_utci(t2_k, 3.0, mrt, Some(td_k), None));
+
+        // Wind outside 0.5 m/s to 17 m/s still gives the extrapolated value.
+        for va in [0.1, 20.0] {
+            let (utci, valid) = calculate_utci_with_validity(t2_k, va, mrt, Some(td_k), None);
+            assert!(!valid);
+            assert!(utci.is_finite());
+            assert_eq!(utci, calculate_utci(t2_k, va, mrt, Some(td_k), None));
+        }
+
+        // The flag agrees with the checked function.
+        let (_, valid) = calculate_utci_with_validity(330.0, 3.0, 330.0, None, Some(20.0));
+        assert_eq!(
+            valid,
+            calculate_utci_checked(330.0, 3.0, 330.0, None, Some(20.0)).is_ok()
+        );
+        assert!(!calculate_utci_with_validity(f64::NAN, 3.0, mrt, Some(td_k), None).1);
+    }
+
+    #[test]
+    fn test_wind_chill_nws_table() {
+        // Cells of the NWS chart as (temperature °F, wind mph, wind chill °F), including ones where
+        // the unrounded value is close to a half degree.
+        let chart = [
+            (40.0, 5.0, 36.0),
+            (0.0, 5.0, -11.0),
+            (-40.0, 5.0, -57.0),
+            (0.0, 15.0, -19.0),
+            (-45.0, 15.0, -77.0),
+            (15.0, 30.0, -5.0),
+            (-20.0, 30.0, -53.0),
+            (35.0, 60.0, 17.0),
+            (-10.0, 60.0, -48.0),
+            (-45.0, 60.0, -98.0),
+        ];
+        for (t2_f, va_mph, expected) in chart {
+            assert_eq!(
+                calculate_wind_chill_nws_table(t2_f, va_mph),
+                expected,
+                "{t2_f}°F at {va_mph} mph"
+            );
+        }
+
+        // Outside the chart the air temperature is unchanged.
+        assert_eq!(calculate_wind_chill_nws_table(55.5, 20.0), 55.5);
+        assert_eq!(calculate_wind_chill_nws_table(20.25, 2.0), 20.25);
+
+        // The same model as the SI function, before rounding.
+        let t2_k = fahrenheit_to_kelvin(0.0);
+        let si = kelvin_to_fahrenheit(calculate_wind_chill(t2_k, 15.0 * 0.44704));
+        assert!((si - calculate_wind_chill_nws_table(0.0, 15.0)).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_saturation_vapour_pressure_buck() {
+        // At 0°C the exponent vanishes, leaving Buck's 6.1121 hPa over water and 6.1115 hPa over ice.
+        let zero = celsius_to_kelvin(0.0);
+        assert_eq!(
+            calculate_saturation_vapour_pressure_buck(zero, Phase::Liquid),
+            6.1121
+        );
+        assert_eq!(
+            calculate_saturation_vapour_pressure_buck(zero, Phase::Ice),
+            6.1115
+        );
+
+        // Tabulated values: 42.452 hPa over water at 30°C and 2.5995 hPa over ice at -10°C.
+        assert_relative_eq!(
+            calculate_saturation_vapour_pressure_buck(celsius_to_kelvin(30.0), Phase::Liquid),
+            42.452,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            calculate_saturation_vapour_pressure_buck(celsius_to_kelvin(-10.0), Phase::Ice),
+            2.5995,
+            epsilon = 1e-4
+        );
+
+        for t2_c in [-20.0, 0.0, 20.0, 40.0, 50.0] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            assert_relative_eq!(
+                calculate_saturation_vapour_pressure_buck(t2_k, Phase::Liquid),
+                calculate_saturation_vapour_pressure(t2_k),
+                max_relative = 5e-4
+            );
+        }
+        for (t2_c, max_relative) in [(-80.0, 3e-3), (-40.0, 1e-3), (-20.0, 6e-4), (0.0, 5e-4)] {
+            let t2_k = celsius_to_kelvin(t2_c);
+            assert_relative_eq!(
+                calculate_saturation_vapour_pressure_buck(t2_k, Phase::Ice),
+                calculate_saturation_vapour_pressure_ice_hardy(t2_k),
+                max_relative = max_relative
+            );
+        }
+    }
+
+    #[test]
+    fn test_dew_point_depression_and_cloud_base() {
+        let (t2_k, td_k) = (celsius_to_kelvin(20.0), celsius_to_kelvin(12.0));
+        assert_relative_eq!(
+            calculate_dew_point_depression(t2_k, td_k),
+            8.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            calculate_cloud_base_height(t2_k, td_k),
+            1000.0,
+            epsilon = 1e-9
+        );
+
+        assert_eq!(calculate_cloud_base_height(290.0, 290.0), 0.0);
+        assert_eq!(calculate_cloud_base_height(290.0, 291.0), 0.0);
+    }
+
+    #[test]
+    fn test_wbgt_with_uncertainty() {
+        let (t2_k, mrt, va, td_k) = (303.15, 318.15, 2.0, 293.15);
+        let sigma_inputs = (0.5, 2.0, 0.3, 0.5);
+        let (wbgt, sigma) = calculate_wbgt_with_uncertainty(t2_k, mrt, va, td_k, sigma_inputs);
+        assert_eq!(wbgt, calculate_wbgt(t2_k, mrt, va, td_k));
+
+        // Exact inputs have no uncertainty
+        let (_, none) = calculate_wbgt_with_uncertainty(t2_k, mrt, va, td_k, (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(none, 0.0);
+
+        // Monte Carlo estimate with normally distributed inputs, from a fixed-seed splitmix64 and
+        // the Box-Muller transform
+        let mut state: u64 = 0x853c_49e6_748f_ea9b;
+        let mut uniform = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            ((z ^ (z >> 31)) >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let mut normal = || {
+            let (u1, u2) = (1.0 - uniform(), uniform());
+            (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+        };
+
+        let n = 20_000;
+        let samples: Vec<f64> = (0..n)
+            .map(|_| {
+                calculate_wbgt(
+                    t2_k + sigma_inputs.0 * normal(),
+                    mrt + sigma_inputs.1 * normal(),
+                    va + sigma_inputs.2 * normal(),
+                    td_k + sigma_inputs.3 * normal(),
+                )
+            })
+            .collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+
+        assert_relative_eq!(mean, wbgt, epsilon = 0.02);
+        assert_relative_eq!(sigma, variance.sqrt(), max_relative = 0.03);
+    }
+
+    #[test]
+    fn test_standard_globe_constants() {
+        use thermofeel_rs::constants::{STANDARD_GLOBE_DIAMETER_M, STANDARD_GLOBE_EMISSIVITY};
+
+        assert_eq!(STANDARD_GLOBE_DIAMETER_M, 0.15);
+        assert_eq!(STANDARD_GLOBE_EMISSIVITY, 0.95);
+
+        // The standard globe functions are the custom ones with these constants
+        let (t2_k, mrt, va) = (300.0, 330.0, 2.0);
+        assert_eq!(
+            calculate_bgt(t2_k, mrt, va),
+            calculate_bgt_custom(
+                t2_k,
+                mrt,
+                va,
+                STANDARD_GLOBE_DIAMETER_M,
+                STANDARD_GLOBE_EMISSIVITY
+            )
+        );
+        let bgt_k = 315.0;
+        assert_eq!(
+            calculate_mrt_from_bgt(t2_k, bgt_k, va),
+            calculate_mrt_from_globe(
+                t2_k,
+                bgt_k,
+                va,
+                STANDARD_GLOBE_DIAMETER_M,
+                STANDARD_GLOBE_EMISSIVITY
+            )
+        );
+    }
+
+    #[test]
+    fn test_wbgt_components() {
+        for (t2_k, mrt, va, td_k) in [
+            (303.15, 318.15, 2.0, 293.15),
+            (308.15, 308.15, 0.5, 298.15),
+            (298.15, 340.0, 5.0, 280.0),
+        ] {
+            let components = calculate_wbgt_components(t2_k, mrt, va, td_k);
+            assert_eq!(components.wbgt, calculate_wbgt(t2_k, mrt, va, td_k));
+            assert_eq!(components.dry_bulb, t2_k);
+            assert_eq!(components.globe, calculate_bgt(t2_k, mrt, va));
+
+            let rh = calculate_relative_humidity_percent(t2_k, td_k);
+            assert_eq!(components.wet_bulb, calculate_wbt(t2_k, rh));
+
+            let weighted =
+                0.7 * components.wet_bulb + 0.2 * components.globe + 0.1 * components.dry_bulb;
+            assert_relative_eq!(weighted, components.wbgt, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dew_point_from_specific_humidity() {
+        // Saturation mixing ratio lines of a skew-T chart: 10 g/kg crosses 14°C at 1000 hPa, and
+        // 2 g/kg crosses -17°C at 500 hPa
+        assert_relative_eq!(
+            calculate_dew_point_from_specific_humidity(0.010, 1000.0),
+            celsius_to_kelvin(14.0),
+            epsilon = 0.3
+        );
+        assert_relative_eq!(
+            calculate_dew_point_from_specific_humidity(0.002, 500.0),
+            celsius_to_kelvin(-17.0),
+            epsilon = 0.3
+        );
+
+        // The same moisture has a lower dew point at lower pressure
+        let sea_level = calculate_dew_point_from_specific_humidity(0.005, 1013.25);
+        let aloft = calculate_dew_point_from_specific_humidity(0.005, 500.0);
+        assert!(aloft < sea_level - 9.0);
+
+        // Consistent with the relative humidity path at the dew point
+        let (q, p) = (0.008, 850.0);
+        let td_k = calculate_dew_point_from_specific_humidity(q, p);
+        assert_relative_eq!(
+            calculate_relative_humidity_from_specific_humidity(td_k, q, p),
+            100.0,
+            epsilon = 1e-9
+        );
+
+        assert!(calculate_dew_point_from_specific_humidity(0.0, 1000.0).is_nan());
+        assert!(calculate_dew_point_from_specific_humidity(-0.001, 1000.0).is_nan());
     }
 }
@@ -0,0 +1,81 @@
+mod common;
+
+use common::*;
+use thermofeel_rs::*;
+
+#[test]
+fn test_every_index_is_finite_for_every_scenario() {
+    for (scenario, inputs) in SCENARIOS {
+        for index in all_indices() {
+            if let Some(value) = index.compute(&inputs) {
+                assert!(
+                    value.is_finite(),
+                    "{} is {value} for {scenario}",
+                    index.name()
+                );
+            }
+        }
+
+        let MeteoInputs {
+            t2_k,
+            td_k,
+            va,
+            mrt,
+        } = inputs;
+        let rh = inputs.relative_humidity();
+        let others = [
+            ("bgt", calculate_bgt(t2_k, mrt, va)),
+            ("humiture", calculate_humiture(t2_k, td_k)),
+            ("wbgt_simple", calculate_wbgt_simple(t2_k, rh)),
+            (
+                "apparent_temperature_noaa",
+                calculate_apparent_temperature_noaa(t2_k, va, rh),
+            ),
+            (
+                "apparent_temperature_steadman1979",
+                calculate_apparent_temperature_steadman1979(t2_k, rh),
+            ),
+            ("feels_like", calculate_feels_like(t2_k, va, rh)),
+            (
+                "wbt_davies_jones",
+                calculate_wbt_method(t2_k, rh, 1013.25, WetBulbMethod::DaviesJones),
+            ),
+        ];
+        for (name, value) in others {
+            assert!(value.is_finite(), "{name} is {value} for {scenario}");
+        }
+    }
+}
+
+#[test]
+fn test_scenarios_are_consistent() {
+    for (scenario, inputs) in SCENARIOS {
+        assert!(inputs.td_k <= inputs.t2_k, "{scenario}");
+        assert!(
+            (0.0..=100.0).contains(&inputs.relative_humidity()),
+            "{scenario}"
+        );
+    }
+}
+
+#[test]
+fn test_scenarios_exercise_each_regime() {
+    let hot = calculate_comfort_summary(
+        HOT_HUMID_TROPICAL.t2_k,
+        HOT_HUMID_TROPICAL.td_k,
+        HOT_HUMID_TROPICAL.va,
+        HOT_HUMID_TROPICAL.mrt,
+    );
+    assert!(hot.utci.unwrap() >= UtciCategory::StrongHeatStress);
+    assert!(hot.humidex.unwrap() >= HumidexCategory::GreatDiscomfort);
+
+    assert!(HeatIndex.compute(&HOT_DRY_DESERT).is_some());
+    assert_eq!(WindChill.compute(&HOT_DRY_DESERT), None);
+
+    let mild = Utci.compute(&MILD_TEMPERATE).unwrap();
+    assert_eq!(classify_utci(mild), Some(UtciCategory::NoThermalStress));
+
+    assert_eq!(HeatIndex.compute(&COLD_WINDY_ARCTIC), None);
+    let wind_chill = WindChill.compute(&COLD_WINDY_ARCTIC).unwrap();
+    assert!(classify_wind_chill(wind_chill).unwrap() >= WindChillRisk::High);
+}
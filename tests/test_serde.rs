@@ -0,0 +1,39 @@
+#![cfg(feature = "serde")]
+
+use approx::assert_relative_eq;
+use thermofeel_rs::*;
+
+#[test]
+fn test_all_indices_to_json() {
+    let indices = calculate_all(303.15, 293.15, 2.0, 313.15);
+    let json = serde_json::to_value(indices).unwrap();
+
+    let fields = [
+        ("utci", Some(indices.utci)),
+        ("wbgt", Some(indices.wbgt)),
+        ("humidex", Some(indices.humidex)),
+        ("apparent_temperature", Some(indices.apparent_temperature)),
+        ("heat_index", indices.heat_index),
+        ("wind_chill", indices.wind_chill),
+        ("wbt", Some(indices.wbt)),
+        ("net", Some(indices.net)),
+    ];
+    assert_eq!(json.as_object().unwrap().len(), fields.len());
+
+    for (name, expected) in fields {
+        match expected {
+            Some(value) => assert_relative_eq!(json[name].as_f64().unwrap(), value, epsilon = 1e-9),
+            None => assert!(json[name].is_null(), "`{name}` should be null"),
+        }
+    }
+    // 30°C is too warm for wind chill.
+    assert!(json["wind_chill"].is_null());
+    assert_relative_eq!(json["humidex"].as_f64().unwrap(), 310.7, epsilon = 0.5);
+}
+
+#[test]
+fn test_all_indices_round_trip() {
+    let indices = calculate_all(265.0, 260.0, 6.0, 270.0);
+    let json = serde_json::to_string(&indices).unwrap();
+    assert_eq!(serde_json::from_str::<AllIndices>(&json).unwrap(), indices);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "simd")]
+
+use approx::assert_relative_eq;
+use thermofeel_rs::*;
+
+#[test]
+fn test_utci_simd_matches_slice() {
+    // Not a multiple of the lane width, so the scalar tail is covered too
+    let n = 10_003;
+    let t2_k: Vec<f64> = (0..n).map(|i| 230.0 + (i % 97) as f64).collect();
+    let va: Vec<f64> = (0..n).map(|i| 0.5 + (i % 33) as f64 * 0.5).collect();
+    let mrt: Vec<f64> = (0..n).map(|i| t2_k[i] - 30.0 + (i % 101) as f64).collect();
+    let td_k: Vec<f64> = (0..n).map(|i| t2_k[i] - (i % 23) as f64).collect();
+
+    let simd = calculate_utci_simd(&t2_k, &va, &mrt, &td_k);
+    let scalar = calculate_utci_slice(&t2_k, &va, &mrt, &td_k);
+    assert_eq!(simd.len(), n);
+    for (s, x) in simd.iter().zip(&scalar) {
+        assert_relative_eq!(s, x, max_relative = 1e-12);
+    }
+}
+
+#[test]
+fn test_utci_simd_nan() {
+    let t2_k = [300.0, f64::NAN, 300.0, 300.0, f64::NAN];
+    let va = [3.0; 5];
+    let mrt = [310.0; 5];
+    let td_k = [290.0; 5];
+
+    let utci = calculate_utci_simd(&t2_k, &va, &mrt, &td_k);
+    assert!(utci[1].is_nan());
+    assert!(utci[4].is_nan());
+    assert_eq!(
+        utci[0],
+        calculate_utci(300.0, 3.0, 310.0, Some(290.0), None)
+    );
+}
+
+#[test]
+fn test_utci_simd_negative_wind_is_calm() {
+    let t2_k = [300.0; 4];
+    let va = [-2.0, 0.0, f64::NAN, 3.0];
+    let mrt = [310.0; 4];
+    let td_k = [290.0; 4];
+
+    let utci = calculate_utci_simd(&t2_k, &va, &mrt, &td_k);
+    assert_eq!(utci[0], utci[1]);
+    assert!(utci[2].is_nan());
+    assert_eq!(
+        utci[1],
+        calculate_utci(300.0, 0.0, 310.0, Some(290.0), None)
+    );
+}
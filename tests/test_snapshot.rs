@@ -0,0 +1,139 @@
+//! Golden snapshot of the whole index set over a grid of conditions.
+//!
+//! Every index is evaluated over the grid and compared against `tests/data/snapshot.csv`, so any
+//! change to a coefficient or formula shows up here even if no hand-curated test covers it. After an
+//! intentional change in output, regenerate the snapshot and review the diff before committing:
+//!
+//! ```text
+//! UPDATE_SNAPSHOT=1 cargo test --test test_snapshot
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use thermofeel_rs::*;
+
+fn snapshot_columns(t2_k: f64, td_k: f64, va: f64, mrt: f64) -> Vec<(&'static str, Option<f64>)> {
+    let rh = calculate_relative_humidity_percent(t2_k, td_k);
+    let bgt = calculate_bgt(t2_k, mrt, va);
+
+    vec![
+        ("t2_k", Some(t2_k)),
+        ("td_k", Some(td_k)),
+        ("va", Some(va)),
+        ("mrt", Some(mrt)),
+        ("rh", Some(rh)),
+        ("es", Some(calculate_saturation_vapour_pressure(t2_k))),
+        (
+            "dew_point",
+            Some(calculate_dew_point_from_relative_humidity(rh, t2_k)),
+        ),
+        ("va_scaled", Some(scale_windspeed(va, 1.1))),
+        (
+            "utci",
+            Some(calculate_utci(t2_k, va, mrt, Some(td_k), None)),
+        ),
+        ("wbgt", Some(calculate_wbgt(t2_k, mrt, va, td_k))),
+        ("wbgt_simple", Some(calculate_wbgt_simple(t2_k, rh))),
+        ("wbt", Some(calculate_wbt(t2_k, rh))),
+        ("bgt", Some(bgt)),
+        ("mrt_from_bgt", Some(calculate_mrt_from_bgt(t2_k, bgt, va))),
+        ("humidex", Some(calculate_humidex(t2_k, td_k))),
+        (
+            "net",
+            Some(calculate_normal_effective_temperature(t2_k, va, rh)),
+        ),
+        (
+            "apparent_temperature",
+            Some(calculate_apparent_temperature(t2_k, va, rh)),
+        ),
+        ("wind_chill", Some(calculate_wind_chill(t2_k, va))),
+        (
+            "wind_chill_siple",
+            Some(calculate_wind_chill_siple(t2_k, va)),
+        ),
+        (
+            "heat_index_simplified",
+            calculate_heat_index_simplified(t2_k, rh),
+        ),
+        (
+            "heat_index_adjusted",
+            calculate_heat_index_adjusted(t2_k, td_k),
+        ),
+        ("feels_like", Some(calculate_feels_like(t2_k, va, rh))),
+    ]
+}
+
+fn render_snapshot() -> String {
+    let mut rows = Vec::new();
+
+    for t2_c in [-20.0, 0.0, 15.0, 25.0, 35.0, 45.0] {
+        for depression in [0.5, 5.0, 15.0] {
+            for va in [0.5, 3.0, 10.0] {
+                for mrt_offset in [0.0, 15.0] {
+                    let t2_k = celsius_to_kelvin(t2_c);
+                    rows.push(snapshot_columns(
+                        t2_k,
+                        t2_k - depression,
+                        va,
+                        t2_k + mrt_offset,
+                    ));
+                }
+            }
+        }
+    }
+
+    let header: Vec<&str> = rows[0].iter().map(|(name, _)| *name).collect();
+    let mut out = header.join(",") + "\n";
+    for row in rows {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|(_, value)| value.map_or(String::new(), |v| format!("{v:e}")))
+            .collect();
+        out += &(fields.join(",") + "\n");
+    }
+    out
+}
+
+#[test]
+fn test_snapshot() {
+    let path = Path::new("tests").join("data").join("snapshot.csv");
+    let actual = render_snapshot();
+
+    if env::var_os("UPDATE_SNAPSHOT").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .expect("missing snapshot, run with UPDATE_SNAPSHOT=1 to create it");
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let header = actual_lines.next().unwrap();
+    assert_eq!(
+        expected_lines.next(),
+        Some(header),
+        "snapshot columns changed"
+    );
+    let names: Vec<&str> = header.split(',').collect();
+
+    for (row, (e, a)) in expected_lines.zip(actual_lines.by_ref()).enumerate() {
+        for ((name, e), a) in names.iter().zip(e.split(',')).zip(a.split(',')) {
+            let matches = match (e.parse::<f64>(), a.parse::<f64>()) {
+                (Ok(e), Ok(a)) => e == a || (e - a).abs() <= 1e-9 * e.abs().max(a.abs()),
+                _ => e == a,
+            };
+            assert!(
+                matches,
+                "row {row}, `{name}`: snapshot has {e:?} but got {a:?}"
+            );
+        }
+    }
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "snapshot row count changed"
+    );
+}
@@ -0,0 +1,68 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use approx::assert_relative_eq;
+use thermofeel_rs::calculate_cos_solar_zenith;
+
+const GREENWICH: (f64, f64) = (51.4769, 0.0);
+
+/// 2024-06-21T00:00:00Z.
+fn midsummer_2024() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1_718_928_000)
+}
+
+fn at_minutes(day: SystemTime, minutes: u64) -> SystemTime {
+    day + Duration::from_secs(minutes * 60)
+}
+
+#[test]
+fn test_cos_solar_zenith_solar_noon() {
+    let (lat, lon) = GREENWICH;
+    let day = midsummer_2024();
+
+    // Solar noon at Greenwich is 12:02 UTC, when the sun is 90 - 51.48 + 23.44 degrees high.
+    let noon = (0..24 * 60)
+        .max_by(|&a, &b| {
+            let cos_a = calculate_cos_solar_zenith(lat, lon, at_minutes(day, a));
+            let cos_b = calculate_cos_solar_zenith(lat, lon, at_minutes(day, b));
+            cos_a.total_cmp(&cos_b)
+        })
+        .unwrap();
+    assert_eq!(noon, 12 * 60 + 2);
+
+    let elevation = 90.0_f64 - lat + 23.44;
+    assert_relative_eq!(
+        calculate_cos_solar_zenith(lat, lon, at_minutes(day, noon)),
+        elevation.to_radians().sin(),
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn test_cos_solar_zenith_sunrise() {
+    let (lat, lon) = GREENWICH;
+    let day = midsummer_2024();
+
+    // Sunrise at Greenwich is 03:43 UTC, when the refracted upper limb reaches the horizon.
+    let sunrise_cos = 90.833_f64.to_radians().cos();
+    let cos_sza = calculate_cos_solar_zenith(lat, lon, at_minutes(day, 3 * 60 + 43));
+    assert_relative_eq!(cos_sza, sunrise_cos, epsilon = 2e-3);
+
+    // Midnight is dark.
+    assert!(calculate_cos_solar_zenith(lat, lon, day) < 0.0);
+}
+
+#[test]
+fn test_cos_solar_zenith_shifts_with_longitude() {
+    // Moving 15 degrees east brings solar noon one hour earlier.
+    let day = midsummer_2024();
+    let noon = at_minutes(day, 12 * 60);
+    assert_relative_eq!(
+        calculate_cos_solar_zenith(0.0, 15.0, noon - Duration::from_secs(3600)),
+        calculate_cos_solar_zenith(0.0, 0.0, noon),
+        epsilon = 1e-4
+    );
+
+    // Before the Unix epoch: the sun is nearly overhead at the equator at the 1969 March equinox.
+    let equinox_1969 = UNIX_EPOCH - Duration::from_secs(286 * 86400 - 12 * 3600);
+    assert!(calculate_cos_solar_zenith(0.0, 0.0, equinox_1969) > 0.999);
+}
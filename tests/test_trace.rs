@@ -0,0 +1,21 @@
+#![cfg(all(feature = "trace", not(target_arch = "wasm32")))]
+
+use thermofeel_rs::*;
+use tracing_test::traced_test;
+
+#[test]
+#[traced_test]
+fn test_utci_emits_trace() {
+    calculate_utci(303.15, 2.0, 313.15, Some(293.15), None);
+
+    assert!(logs_contain(
+        "calculate_utci{t2_k=303.15 va=2.0 mrt=313.15}"
+    ));
+    assert!(logs_contain("wvp="));
+    assert!(logs_contain("utci_c="));
+    // The relative humidity used for the vapour pressure is traced inside the UTCI span.
+    assert!(logs_contain(
+        "calculate_relative_humidity_percent{t2_k=303.15 td_k=293.15}"
+    ));
+    assert!(logs_contain("es="));
+}
@@ -0,0 +1,24 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+use thermofeel_rs::wasm;
+
+#[wasm_bindgen_test]
+fn test_wasm_utci() {
+    let utci = wasm::calculate_utci(309.0, 3.0, 310.0, None, Some(12.0));
+    assert!((utci.as_f64().unwrap() - 307.76473586).abs() < 1e-5);
+
+    let utci = wasm::calculate_utci(309.0, 3.0, 310.0, None, None);
+    assert_eq!(utci, JsValue::NULL);
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_heat_index_simplified() {
+    let hi = wasm::calculate_heat_index_simplified(thermofeel_rs::celsius_to_kelvin(21.0), 80.0);
+    assert!((hi.as_f64().unwrap() - 294.68866082).abs() < 1e-6);
+
+    let hi = wasm::calculate_heat_index_simplified(thermofeel_rs::celsius_to_kelvin(10.0), 80.0);
+    assert_eq!(hi, JsValue::NULL);
+}